@@ -0,0 +1,34 @@
+import { NextResponse } from "next/server";
+import { initDatabase } from "@/lib/db";
+import recordingService from "@/lib/services/recording-service";
+import { SettingsModel } from "@/lib/models";
+
+// Recordings without new data for this long are reported as stalled when the watchdog is disabled
+const DEFAULT_STALL_SECONDS = 300;
+
+// GET /api/health - Liveness probe: 200 while healthy, 503 if the database is unavailable or a recording has stalled
+export async function GET() {
+  let stallSeconds: number;
+  try {
+    initDatabase();
+    stallSeconds = SettingsModel.get().stall_timeout_seconds || DEFAULT_STALL_SECONDS;
+  } catch (error) {
+    console.error("Health check failed:", error);
+    return NextResponse.json(
+      { status: "error", error: "Database unavailable" },
+      { status: 503 }
+    );
+  }
+
+  const recordings = recordingService.getRecordingHealth();
+  const stalledRecordings = recordings.filter(r => r.secondsSinceLastData >= stallSeconds);
+
+  return NextResponse.json(
+    {
+      status: stalledRecordings.length > 0 ? "stalled" : "ok",
+      activeRecordings: recordings.length,
+      stalledRecordings,
+    },
+    { status: stalledRecordings.length > 0 ? 503 : 200 }
+  );
+}
@@ -2,7 +2,10 @@ import { NextRequest, NextResponse } from "next/server";
 import { initDatabase } from "@/lib/db";
 import { RecordingLogModel } from "@/lib/models";
 
+const LOG_LEVELS = ["info", "warn", "error", "success"];
+
 // GET /api/logs - Get recent logs
+// Optional filters: ?level=warn,error and ?streamer=<username>
 export async function GET(request: NextRequest) {
   try {
     initDatabase();
@@ -11,7 +14,31 @@ export async function GET(request: NextRequest) {
       ? parseInt(searchParams.get("limit")!) 
       : 50;
 
-    const logs = RecordingLogModel.getRecent(limit);
+    const levelParam = searchParams.get("level");
+    // Usernames are stored lowercased
+    const streamer = searchParams.get("streamer")?.trim().toLowerCase();
+
+    if (!levelParam && !streamer) {
+      const logs = RecordingLogModel.getRecent(limit);
+      return NextResponse.json(logs);
+    }
+
+    const levels = levelParam
+      ? levelParam.split(",").map(l => l.trim()).filter(Boolean)
+      : [];
+    const invalidLevel = levels.find(l => !LOG_LEVELS.includes(l));
+    if (invalidLevel) {
+      return NextResponse.json(
+        { error: `Invalid level "${invalidLevel}". Must be one of: ${LOG_LEVELS.join(", ")}` },
+        { status: 400 }
+      );
+    }
+
+    const logs = RecordingLogModel.findAll({
+      level: levels.length > 0 ? levels : undefined,
+      streamerUsername: streamer || undefined,
+      limit,
+    });
     return NextResponse.json(logs);
   } catch (error) {
     console.error("Failed to get logs:", error);
@@ -0,0 +1,67 @@
+import { NextRequest, NextResponse } from "next/server";
+import { initDatabase } from "@/lib/db";
+import { RecordingModel } from "@/lib/models";
+import { toCsv } from "@/lib/utils/csv";
+
+const EXPORT_COLUMNS = [
+  "id",
+  "streamer_username",
+  "stream_title",
+  "stream_category",
+  "quality",
+  "status",
+  "started_at",
+  "ended_at",
+  "duration_seconds",
+  "file_size_bytes",
+  "file_path",
+  "error_message",
+] as const;
+
+// GET /api/recordings/export - Download a report of all recordings as CSV or JSON
+export async function GET(request: NextRequest) {
+  try {
+    initDatabase();
+    const { searchParams } = new URL(request.url);
+
+    const format = searchParams.get("format") || "csv";
+    if (format !== "csv" && format !== "json") {
+      return NextResponse.json(
+        { error: "Invalid format. Use csv or json" },
+        { status: 400 }
+      );
+    }
+
+    const recordings = RecordingModel.findAll({
+      status: searchParams.get("status") || undefined,
+      streamerId: searchParams.get("streamerId")
+        ? parseInt(searchParams.get("streamerId")!)
+        : undefined,
+    });
+
+    const rows = recordings.map((recording) => {
+      const row: Record<string, unknown> = {};
+      for (const column of EXPORT_COLUMNS) {
+        row[column] = recording[column];
+      }
+      return row;
+    });
+
+    const body = format === "csv"
+      ? toCsv([...EXPORT_COLUMNS], rows)
+      : JSON.stringify(rows, null, 2);
+
+    return new NextResponse(body, {
+      headers: {
+        "Content-Type": format === "csv" ? "text/csv; charset=utf-8" : "application/json",
+        "Content-Disposition": `attachment; filename="recordings.${format}"`,
+      },
+    });
+  } catch (error) {
+    console.error("Failed to export recordings:", error);
+    return NextResponse.json(
+      { error: "Failed to export recordings" },
+      { status: 500 }
+    );
+  }
+}
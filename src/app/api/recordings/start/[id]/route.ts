@@ -2,8 +2,10 @@ import { NextResponse } from "next/server";
 import { initDatabase } from "@/lib/db";
 import { StreamerModel } from "@/lib/models";
 import recordingService from "@/lib/services/recording-service";
+import { parseStopAt } from "@/lib/utils/schedule";
 
 // POST /api/recordings/start/[id] - Start recording a streamer
+// Optional JSON body: { "stopAt": "22:00" } or { "stopAt": "2026-01-15T22:00" }
 export async function POST(
   request: Request,
   { params }: { params: Promise<{ id: string }> }
@@ -35,7 +37,26 @@ export async function POST(
       );
     }
 
-    const recordingId = await recordingService.startRecording(streamerId);
+    const body = await request.json().catch(() => ({}));
+    let stopAt: Date | undefined;
+    if (body?.stopAt !== undefined && body?.stopAt !== null && body?.stopAt !== "") {
+      const parsed = typeof body.stopAt === "string" ? parseStopAt(body.stopAt) : null;
+      if (!parsed) {
+        return NextResponse.json(
+          { error: "Invalid stopAt. Use HH:MM or an ISO date-time" },
+          { status: 400 }
+        );
+      }
+      if (parsed <= new Date()) {
+        return NextResponse.json(
+          { error: `stopAt ${parsed.toISOString()} is in the past` },
+          { status: 400 }
+        );
+      }
+      stopAt = parsed;
+    }
+
+    const recordingId = await recordingService.startRecording(streamerId, { stopAt });
     return NextResponse.json({ recordingId });
   } catch (error: any) {
     console.error("Failed to start recording:", error);
@@ -1,6 +1,7 @@
 import { NextRequest, NextResponse } from 'next/server';
 import { SettingsModel } from '@/lib/models/settings';
 import { getDiskSpaceStatus } from '@/lib/utils/disk-space';
+import { isValidWebhookUrl } from '@/lib/utils/webhook';
 import path from 'path';
 
 const RECORDINGS_DIR = process.env.RECORDINGS_DIR || path.join(process.cwd(), 'recordings');
@@ -18,6 +19,15 @@ export async function GET() {
         maxTotalRecordingsMb: settings.max_total_recordings_mb,
         maxRecordingDurationHours: settings.max_recording_duration_hours,
         checkIntervalSeconds: settings.check_interval_seconds,
+        stallTimeoutSeconds: settings.stall_timeout_seconds,
+        notifyWebhookUrl: settings.notify_webhook_url || '',
+        qualityInFilename: !!settings.quality_in_filename,
+        rotateDaily: !!settings.rotate_daily,
+        rotateOnTitleChange: !!settings.rotate_on_title_change,
+        perStreamerDirs: !!settings.per_streamer_dirs,
+        recordChat: !!settings.record_chat,
+        metadataSidecar: !!settings.metadata_sidecar,
+        allowAudioOnly: !!settings.allow_audio_only,
       },
       diskStatus: {
         total: diskStatus.total,
@@ -99,6 +109,56 @@ export async function POST(request: NextRequest) {
       updates.check_interval_seconds = value;
     }
 
+    if (body.stallTimeoutSeconds !== undefined) {
+      const value = parseInt(body.stallTimeoutSeconds, 10);
+      if (isNaN(value) || value < 0) {
+        return NextResponse.json(
+          { error: 'stallTimeoutSeconds must be a non-negative number (0 = disabled)' },
+          { status: 400 }
+        );
+      }
+      updates.stall_timeout_seconds = value;
+    }
+
+    if (body.notifyWebhookUrl !== undefined) {
+      const value = String(body.notifyWebhookUrl ?? '').trim();
+      if (value && !isValidWebhookUrl(value)) {
+        return NextResponse.json(
+          { error: 'notifyWebhookUrl must be an http(s) URL (leave empty to disable)' },
+          { status: 400 }
+        );
+      }
+      updates.notify_webhook_url = value || null;
+    }
+
+    if (body.qualityInFilename !== undefined) {
+      updates.quality_in_filename = !!body.qualityInFilename;
+    }
+
+    if (body.rotateDaily !== undefined) {
+      updates.rotate_daily = !!body.rotateDaily;
+    }
+
+    if (body.rotateOnTitleChange !== undefined) {
+      updates.rotate_on_title_change = !!body.rotateOnTitleChange;
+    }
+
+    if (body.perStreamerDirs !== undefined) {
+      updates.per_streamer_dirs = !!body.perStreamerDirs;
+    }
+
+    if (body.recordChat !== undefined) {
+      updates.record_chat = !!body.recordChat;
+    }
+
+    if (body.metadataSidecar !== undefined) {
+      updates.metadata_sidecar = !!body.metadataSidecar;
+    }
+
+    if (body.allowAudioOnly !== undefined) {
+      updates.allow_audio_only = !!body.allowAudioOnly;
+    }
+
     const updatedSettings = SettingsModel.update(updates);
 
     if (!updatedSettings) {
@@ -115,6 +175,15 @@ export async function POST(request: NextRequest) {
         maxTotalRecordingsMb: updatedSettings.max_total_recordings_mb,
         maxRecordingDurationHours: updatedSettings.max_recording_duration_hours,
         checkIntervalSeconds: updatedSettings.check_interval_seconds,
+        stallTimeoutSeconds: updatedSettings.stall_timeout_seconds,
+        notifyWebhookUrl: updatedSettings.notify_webhook_url || '',
+        qualityInFilename: !!updatedSettings.quality_in_filename,
+        rotateDaily: !!updatedSettings.rotate_daily,
+        rotateOnTitleChange: !!updatedSettings.rotate_on_title_change,
+        perStreamerDirs: !!updatedSettings.per_streamer_dirs,
+        recordChat: !!updatedSettings.record_chat,
+        metadataSidecar: !!updatedSettings.metadata_sidecar,
+        allowAudioOnly: !!updatedSettings.allow_audio_only,
       },
     });
   } catch (error) {
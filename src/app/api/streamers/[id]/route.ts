@@ -1,6 +1,7 @@
 import { NextRequest, NextResponse } from "next/server";
 import { initDatabase } from "@/lib/db";
 import { StreamerModel } from "@/lib/models";
+import { parseSchedule } from "@/lib/utils/schedule";
 
 // GET /api/streamers/[id] - Get a specific streamer
 export async function GET(
@@ -56,6 +57,14 @@ export async function PATCH(
     }
 
     const body = await request.json();
+
+    if (body.record_schedule && !parseSchedule(String(body.record_schedule))) {
+      return NextResponse.json(
+        { error: "Schedule must be a local time window like 20:00-22:00" },
+        { status: 400 }
+      );
+    }
+
     const streamer = StreamerModel.update(streamerId, body);
     
     if (!streamer) {
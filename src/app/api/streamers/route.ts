@@ -2,6 +2,8 @@ import { NextRequest, NextResponse } from "next/server";
 import { initDatabase } from "@/lib/db";
 import { StreamerModel } from "@/lib/models";
 import recordingService from "@/lib/services/recording-service";
+import { parseTwitchUsername } from "@/lib/utils/twitch-username";
+import { parseSchedule } from "@/lib/utils/schedule";
 
 // Fetch Twitch avatar using decapi.me (free, no auth required)
 async function fetchTwitchAvatar(username: string): Promise<string | null> {
@@ -52,8 +54,24 @@ export async function POST(request: NextRequest) {
       );
     }
 
+    // Accept a bare login or a twitch.tv channel URL
+    const username = parseTwitchUsername(String(body.username));
+    if (!username) {
+      return NextResponse.json(
+        { error: "Username must be a Twitch login (letters, numbers and underscores) or a twitch.tv channel URL" },
+        { status: 400 }
+      );
+    }
+
+    if (body.record_schedule && !parseSchedule(String(body.record_schedule))) {
+      return NextResponse.json(
+        { error: "Schedule must be a local time window like 20:00-22:00" },
+        { status: 400 }
+      );
+    }
+
     // Check if streamer already exists
-    const existing = StreamerModel.findByUsername(body.username);
+    const existing = StreamerModel.findByUsername(username);
     if (existing) {
       return NextResponse.json(
         { error: "Streamer already exists" },
@@ -62,14 +80,15 @@ export async function POST(request: NextRequest) {
     }
 
     // Fetch avatar from Twitch
-    const avatarUrl = await fetchTwitchAvatar(body.username);
+    const avatarUrl = await fetchTwitchAvatar(username);
 
     const streamer = StreamerModel.create({
-      username: body.username,
+      username,
       display_name: body.display_name,
       avatar_url: avatarUrl || undefined,
       auto_record: body.auto_record,
       quality_preference: body.quality_preference,
+      record_schedule: body.record_schedule || null,
     });
 
     // If auto_record is enabled, immediately check if the streamer is live
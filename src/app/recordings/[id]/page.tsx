@@ -59,9 +59,10 @@ export default function RecordingDetailPage() {
 
   useEffect(() => {
     if (recording?.file_path) {
-      const filename = recording.file_path.split("/").pop();
-      if (filename) {
-        setVideoUrl(`/recordings/${filename}`);
+      // file_path is relative to the app root and may include a streamer subdirectory
+      const relativePath = recording.file_path.replace(/^recordings\//, "");
+      if (relativePath) {
+        setVideoUrl(`/recordings/${relativePath}`);
       }
     }
   }, [recording]);
@@ -206,7 +207,11 @@ export default function RecordingDetailPage() {
   }
 
   const filename = recording.file_path.split("/").pop() || recording.file_path;
-  const canDownload = recording.status === "completed" || recording.status === "stopped";
+  // Failed recordings can still have a usable partial file
+  const canDownload =
+    recording.status === "completed" ||
+    recording.status === "stopped" ||
+    (recording.status === "error" && recording.file_size_bytes > 0);
 
   return (
     <div className="flex h-screen bg-background">
@@ -30,6 +30,7 @@ import {
   Trash2,
   Filter,
   Square,
+  Download,
 } from "lucide-react";
 import { toast } from "sonner";
 
@@ -187,6 +188,12 @@ export default function RecordingsPage() {
                 View and manage your recorded streams
               </p>
             </div>
+            <Button variant="outline" asChild className="cursor-pointer">
+              <a href="/api/recordings/export?format=csv" download>
+                <Download className="w-4 h-4 mr-2" />
+                Export CSV
+              </a>
+            </Button>
           </div>
 
           {/* Filters */}
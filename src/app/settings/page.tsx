@@ -63,6 +63,15 @@ interface AppSettings {
   maxTotalRecordingsMb: number;
   maxRecordingDurationHours: number;
   checkIntervalSeconds: number;
+  stallTimeoutSeconds: number;
+  notifyWebhookUrl: string;
+  qualityInFilename: boolean;
+  rotateDaily: boolean;
+  rotateOnTitleChange: boolean;
+  perStreamerDirs: boolean;
+  recordChat: boolean;
+  metadataSidecar: boolean;
+  allowAudioOnly: boolean;
 }
 
 interface DiskStatus {
@@ -81,6 +90,15 @@ export default function SettingsPage() {
     maxTotalRecordingsMb: 0,
     maxRecordingDurationHours: 0,
     checkIntervalSeconds: 60,
+    stallTimeoutSeconds: 300,
+    notifyWebhookUrl: "",
+    qualityInFilename: false,
+    rotateDaily: false,
+    rotateOnTitleChange: false,
+    perStreamerDirs: false,
+    recordChat: false,
+    metadataSidecar: false,
+    allowAudioOnly: false,
   });
   const [diskStatus, setDiskStatus] = useState<DiskStatus | null>(null);
   const [loading, setLoading] = useState(true);
@@ -362,7 +380,7 @@ export default function SettingsPage() {
                       </span>
                     </div>
                     <p className="text-xs text-muted-foreground">
-                      Recordings will not start if free space falls below this limit (0 = no limit)
+                      Recordings will not start, and active recordings are stopped, if free space falls below this limit (0 = no limit)
                     </p>
                   </div>
 
@@ -408,7 +426,7 @@ export default function SettingsPage() {
                       </span>
                     </div>
                     <p className="text-xs text-muted-foreground">
-                      New recordings will not start if total size exceeds this limit (0 = unlimited)
+                      New recordings will not start, and active recordings are stopped, once total size reaches this limit (0 = unlimited)
                     </p>
                   </div>
 
@@ -505,6 +523,28 @@ export default function SettingsPage() {
                       How often to check if streamers are live (30-3600 seconds)
                     </p>
                   </div>
+
+                  <div className="space-y-2">
+                    <Label htmlFor="stallTimeoutSeconds">Stall Timeout (seconds)</Label>
+                    <div className="flex items-center gap-3">
+                      <Input
+                        id="stallTimeoutSeconds"
+                        type="number"
+                        min="0"
+                        value={settings.stallTimeoutSeconds}
+                        onChange={(e) =>
+                          setSettings({ ...settings, stallTimeoutSeconds: parseInt(e.target.value) || 0 })
+                        }
+                        className="max-w-[150px]"
+                      />
+                      <span className="text-sm text-muted-foreground">
+                        {settings.stallTimeoutSeconds === 0 ? 'Disabled' : `${settings.stallTimeoutSeconds} seconds`}
+                      </span>
+                    </div>
+                    <p className="text-xs text-muted-foreground">
+                      Restart a recording if its file stops growing for this long. Ad breaks are filtered out, so keep this above the longest ad break (0 = disabled)
+                    </p>
+                  </div>
                 </CardContent>
               </Card>
 
@@ -537,6 +577,132 @@ export default function SettingsPage() {
                       Recordings are saved to the ./recordings directory (configured via RECORDINGS_DIR env var)
                     </p>
                   </div>
+
+                  <div className="flex items-center justify-between p-4 rounded-lg bg-muted/50 border">
+                    <div>
+                      <Label htmlFor="perStreamerDirs" className="cursor-pointer font-medium">
+                        Per-Streamer Folders
+                      </Label>
+                      <p className="text-sm text-muted-foreground">
+                        Save new recordings to a subdirectory per streamer, e.g. recordings/streamer/
+                      </p>
+                    </div>
+                    <Switch
+                      id="perStreamerDirs"
+                      checked={settings.perStreamerDirs}
+                      onCheckedChange={(checked) =>
+                        setSettings({ ...settings, perStreamerDirs: checked })
+                      }
+                    />
+                  </div>
+
+                  <div className="flex items-center justify-between p-4 rounded-lg bg-muted/50 border">
+                    <div>
+                      <Label htmlFor="qualityInFilename" className="cursor-pointer font-medium">
+                        Quality in Filename
+                      </Label>
+                      <p className="text-sm text-muted-foreground">
+                        Append the quality streamlink selected, e.g. streamer_..._1080p60.mp4
+                      </p>
+                    </div>
+                    <Switch
+                      id="qualityInFilename"
+                      checked={settings.qualityInFilename}
+                      onCheckedChange={(checked) =>
+                        setSettings({ ...settings, qualityInFilename: checked })
+                      }
+                    />
+                  </div>
+
+                  <div className="flex items-center justify-between p-4 rounded-lg bg-muted/50 border">
+                    <div>
+                      <Label htmlFor="recordChat" className="cursor-pointer font-medium">
+                        Record Chat
+                      </Label>
+                      <p className="text-sm text-muted-foreground">
+                        Save a timestamped chat transcript (.chat.txt) next to each recording
+                      </p>
+                    </div>
+                    <Switch
+                      id="recordChat"
+                      checked={settings.recordChat}
+                      onCheckedChange={(checked) =>
+                        setSettings({ ...settings, recordChat: checked })
+                      }
+                    />
+                  </div>
+
+                  <div className="flex items-center justify-between p-4 rounded-lg bg-muted/50 border">
+                    <div>
+                      <Label htmlFor="metadataSidecar" className="cursor-pointer font-medium">
+                        Metadata Sidecar
+                      </Label>
+                      <p className="text-sm text-muted-foreground">
+                        Write a .json file next to each finished recording with its title, quality, times and size
+                      </p>
+                    </div>
+                    <Switch
+                      id="metadataSidecar"
+                      checked={settings.metadataSidecar}
+                      onCheckedChange={(checked) =>
+                        setSettings({ ...settings, metadataSidecar: checked })
+                      }
+                    />
+                  </div>
+
+                  <div className="flex items-center justify-between p-4 rounded-lg bg-muted/50 border">
+                    <div>
+                      <Label htmlFor="allowAudioOnly" className="cursor-pointer font-medium">
+                        Allow Audio-only Recordings
+                      </Label>
+                      <p className="text-sm text-muted-foreground">
+                        Record broadcasts that only offer audio. When off, they are skipped until they go offline and a recording that falls back to audio stops with an error
+                      </p>
+                    </div>
+                    <Switch
+                      id="allowAudioOnly"
+                      checked={settings.allowAudioOnly}
+                      onCheckedChange={(checked) =>
+                        setSettings({ ...settings, allowAudioOnly: checked })
+                      }
+                    />
+                  </div>
+
+                  <div className="flex items-center justify-between p-4 rounded-lg bg-muted/50 border">
+                    <div>
+                      <Label htmlFor="rotateDaily" className="cursor-pointer font-medium">
+                        Rotate Daily
+                      </Label>
+                      <p className="text-sm text-muted-foreground">
+                        Start a new file at local midnight while a stream is still live
+                      </p>
+                    </div>
+                    <Switch
+                      id="rotateDaily"
+                      checked={settings.rotateDaily}
+                      onCheckedChange={(checked) =>
+                        setSettings({ ...settings, rotateDaily: checked })
+                      }
+                    />
+                  </div>
+
+                  <div className="flex items-center justify-between p-4 rounded-lg bg-muted/50 border">
+                    <div>
+                      <Label htmlFor="rotateOnTitleChange" className="cursor-pointer font-medium">
+                        Rotate on Title Change
+                      </Label>
+                      <p className="text-sm text-muted-foreground">
+                        Start a new file, named after the new title, when the stream title or category changes (checked every minute)
+                      </p>
+                    </div>
+                    <Switch
+                      id="rotateOnTitleChange"
+                      checked={settings.rotateOnTitleChange}
+                      onCheckedChange={(checked) =>
+                        setSettings({ ...settings, rotateOnTitleChange: checked })
+                      }
+                    />
+                  </div>
                 </CardContent>
               </Card>
 
@@ -552,25 +718,21 @@ export default function SettingsPage() {
                   </CardDescription>
                 </CardHeader>
                 <CardContent className="space-y-5">
-                  <div className="flex items-center justify-between p-4 rounded-lg bg-muted/50 border">
-                    <div>
-                      <Label htmlFor="notificationsEnabled" className="cursor-pointer font-medium">
-                        Enable Notifications
-                      </Label>
-                      <p className="text-sm text-muted-foreground">
-                        Receive notifications when recordings start/stop
-                      </p>
-                    </div>
-                    <Switch
-                      id="notificationsEnabled"
-                      checked={false}
-                      disabled
-                      className="cursor-not-allowed"
+                  <div className="space-y-2">
+                    <Label htmlFor="notifyWebhookUrl">Webhook URL</Label>
+                    <Input
+                      id="notifyWebhookUrl"
+                      type="url"
+                      placeholder="https://discord.com/api/webhooks/..."
+                      value={settings.notifyWebhookUrl}
+                      onChange={(e) =>
+                        setSettings({ ...settings, notifyWebhookUrl: e.target.value })
+                      }
                     />
+                    <p className="text-xs text-muted-foreground">
+                      A JSON payload is POSTed here when a recording starts or ends. Works with Discord and Slack webhooks (leave empty to disable)
+                    </p>
                   </div>
-                  <p className="text-xs text-muted-foreground">
-                    Notification settings coming soon
-                  </p>
                 </CardContent>
               </Card>
 
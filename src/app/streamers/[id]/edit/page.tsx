@@ -44,6 +44,7 @@ interface Streamer {
   is_active: boolean;
   auto_record: boolean;
   quality_preference: string;
+  record_schedule: string | null;
   created_at: string;
 }
 
@@ -75,6 +76,7 @@ export default function EditStreamerPage() {
     is_active: true,
     auto_record: true,
     quality_preference: "best",
+    record_schedule: "",
   });
 
   useEffect(() => {
@@ -93,6 +95,7 @@ export default function EditStreamerPage() {
           is_active: data.is_active,
           auto_record: data.auto_record,
           quality_preference: data.quality_preference,
+          record_schedule: data.record_schedule || "",
         });
       } else {
         toast.error("Streamer not found");
@@ -304,6 +307,21 @@ export default function EditStreamerPage() {
                     </Select>
                   </div>
 
+                  <div className="space-y-2">
+                    <Label htmlFor="record_schedule">Recording Schedule</Label>
+                    <Input
+                      id="record_schedule"
+                      placeholder="e.g., 20:00-22:00"
+                      value={formData.record_schedule}
+                      onChange={(e) =>
+                        setFormData({ ...formData, record_schedule: e.target.value })
+                      }
+                    />
+                    <p className="text-xs text-muted-foreground">
+                      Only auto-record within this daily window (server local time) and stop when it ends. Leave empty to record any time.
+                    </p>
+                  </div>
+
                   <div className="flex items-center justify-between p-4 border rounded-lg">
                     <div>
                       <Label htmlFor="is_active" className="cursor-pointer">
@@ -107,7 +107,8 @@ export default function StreamersPage() {
       });
 
       if (response.ok) {
-        toast.success(`Added ${newStreamer.username}`);
+        const streamer = await response.json();
+        toast.success(`Added ${streamer.username}`);
         setNewStreamer({
           username: "",
           display_name: "",
@@ -207,7 +208,7 @@ export default function StreamersPage() {
                 <DialogHeader>
                   <DialogTitle>Add New Streamer</DialogTitle>
                   <DialogDescription>
-                    Enter the Twitch username of the streamer you want to record.
+                    Enter the Twitch username or channel URL of the streamer you want to record.
                   </DialogDescription>
                 </DialogHeader>
                 <div className="space-y-4 py-4">
@@ -34,6 +34,7 @@ export function initDatabase() {
       is_active BOOLEAN DEFAULT 1,
       auto_record BOOLEAN DEFAULT 1,
       quality_preference TEXT DEFAULT 'best',
+      record_schedule TEXT,
       created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
       updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
     )
@@ -83,6 +84,14 @@ export function initDatabase() {
     // Column already exists, ignore error
   }
 
+  // Migration: Add record_schedule column to streamers table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE streamers ADD COLUMN record_schedule TEXT`);
+    console.log('Migration: Added record_schedule column to streamers table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
   // Recording logs table for real-time activity
   db.exec(`
     CREATE TABLE IF NOT EXISTS recording_logs (
@@ -120,6 +129,15 @@ export function initDatabase() {
       max_total_recordings_mb INTEGER DEFAULT 0,
       max_recording_duration_hours INTEGER DEFAULT 0,
       check_interval_seconds INTEGER DEFAULT 60,
+      stall_timeout_seconds INTEGER DEFAULT 300,
+      notify_webhook_url TEXT,
+      quality_in_filename INTEGER DEFAULT 0,
+      rotate_daily INTEGER DEFAULT 0,
+      rotate_on_title_change INTEGER DEFAULT 0,
+      per_streamer_dirs INTEGER DEFAULT 0,
+      record_chat INTEGER DEFAULT 0,
+      metadata_sidecar INTEGER DEFAULT 0,
+      allow_audio_only INTEGER DEFAULT 0,
       updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
     )
   `);
@@ -129,6 +147,78 @@ export function initDatabase() {
     INSERT OR IGNORE INTO settings (id) VALUES (1)
   `);
 
+  // Migration: Add stall_timeout_seconds column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN stall_timeout_seconds INTEGER DEFAULT 300`);
+    console.log('Migration: Added stall_timeout_seconds column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
+  // Migration: Add notify_webhook_url column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN notify_webhook_url TEXT`);
+    console.log('Migration: Added notify_webhook_url column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
+  // Migration: Add quality_in_filename column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN quality_in_filename INTEGER DEFAULT 0`);
+    console.log('Migration: Added quality_in_filename column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
+  // Migration: Add rotate_daily column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN rotate_daily INTEGER DEFAULT 0`);
+    console.log('Migration: Added rotate_daily column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
+  // Migration: Add rotate_on_title_change column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN rotate_on_title_change INTEGER DEFAULT 0`);
+    console.log('Migration: Added rotate_on_title_change column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
+  // Migration: Add per_streamer_dirs column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN per_streamer_dirs INTEGER DEFAULT 0`);
+    console.log('Migration: Added per_streamer_dirs column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
+  // Migration: Add record_chat column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN record_chat INTEGER DEFAULT 0`);
+    console.log('Migration: Added record_chat column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
+  // Migration: Add metadata_sidecar column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN metadata_sidecar INTEGER DEFAULT 0`);
+    console.log('Migration: Added metadata_sidecar column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
+  // Migration: Add allow_audio_only column to settings table if it doesn't exist
+  try {
+    db.exec(`ALTER TABLE settings ADD COLUMN allow_audio_only INTEGER DEFAULT 0`);
+    console.log('Migration: Added allow_audio_only column to settings table');
+  } catch (e) {
+    // Column already exists, ignore error
+  }
+
   isInitialized = true;
   console.log('Database initialized at:', DB_PATH);
 }
@@ -153,6 +153,15 @@ describe('RecordingLogModel', () => {
       expect(logs[0].message).toBe('Error message');
     });
 
+    it('should filter by multiple levels', () => {
+      RecordingLogModel.create({ message: 'Info message', level: 'info' });
+      RecordingLogModel.create({ message: 'Error message', level: 'error' });
+      RecordingLogModel.create({ message: 'Warn message', level: 'warn' });
+
+      const logs = RecordingLogModel.findAll({ level: ['warn', 'error'] });
+      expect(logs.map(l => l.message).sort()).toEqual(['Error message', 'Warn message']);
+    });
+
     it('should apply limit', () => {
       for (let i = 0; i < 5; i++) {
         RecordingLogModel.create({ message: `Message ${i}` });
@@ -30,6 +30,15 @@ describe('SettingsModel', () => {
       expect(settings.max_total_recordings_mb).toBe(0);
       expect(settings.max_recording_duration_hours).toBe(0);
       expect(settings.check_interval_seconds).toBe(60);
+      expect(settings.stall_timeout_seconds).toBe(300);
+      expect(settings.notify_webhook_url).toBeNull();
+      expect(settings.quality_in_filename).toBeFalsy();
+      expect(settings.rotate_daily).toBeFalsy();
+      expect(settings.rotate_on_title_change).toBeFalsy();
+      expect(settings.per_streamer_dirs).toBeFalsy();
+      expect(settings.record_chat).toBeFalsy();
+      expect(settings.metadata_sidecar).toBeFalsy();
+      expect(settings.allow_audio_only).toBeFalsy();
     });
   });
 
@@ -69,6 +78,72 @@ describe('SettingsModel', () => {
       expect(updated?.check_interval_seconds).toBe(120);
     });
 
+    it('should update stall_timeout_seconds', () => {
+      const updated = SettingsModel.update({ stall_timeout_seconds: 0 });
+
+      expect(updated).toBeDefined();
+      expect(updated?.stall_timeout_seconds).toBe(0);
+    });
+
+    it('should update and clear notify_webhook_url', () => {
+      const updated = SettingsModel.update({ notify_webhook_url: 'https://example.com/hook' });
+
+      expect(updated?.notify_webhook_url).toBe('https://example.com/hook');
+
+      const cleared = SettingsModel.update({ notify_webhook_url: null });
+
+      expect(cleared?.notify_webhook_url).toBeNull();
+    });
+
+    it('should update quality_in_filename', () => {
+      const updated = SettingsModel.update({ quality_in_filename: true });
+
+      expect(updated).toBeDefined();
+      expect(updated?.quality_in_filename).toBeTruthy();
+    });
+
+    it('should update rotate_daily', () => {
+      const updated = SettingsModel.update({ rotate_daily: true });
+
+      expect(updated).toBeDefined();
+      expect(updated?.rotate_daily).toBeTruthy();
+    });
+
+    it('should update rotate_on_title_change', () => {
+      const updated = SettingsModel.update({ rotate_on_title_change: true });
+
+      expect(updated).toBeDefined();
+      expect(updated?.rotate_on_title_change).toBeTruthy();
+    });
+
+    it('should update per_streamer_dirs', () => {
+      const updated = SettingsModel.update({ per_streamer_dirs: true });
+
+      expect(updated).toBeDefined();
+      expect(updated?.per_streamer_dirs).toBeTruthy();
+    });
+
+    it('should update record_chat', () => {
+      const updated = SettingsModel.update({ record_chat: true });
+
+      expect(updated).toBeDefined();
+      expect(updated?.record_chat).toBeTruthy();
+    });
+
+    it('should update metadata_sidecar', () => {
+      const updated = SettingsModel.update({ metadata_sidecar: true });
+
+      expect(updated).toBeDefined();
+      expect(updated?.metadata_sidecar).toBeTruthy();
+    });
+
+    it('should update allow_audio_only', () => {
+      const updated = SettingsModel.update({ allow_audio_only: true });
+
+      expect(updated).toBeDefined();
+      expect(updated?.allow_audio_only).toBeTruthy();
+    });
+
     it('should update multiple fields at once', () => {
       const updated = SettingsModel.update({
         min_free_disk_mb: 8000,
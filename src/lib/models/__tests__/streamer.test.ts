@@ -23,6 +23,7 @@ describe('StreamerModel', () => {
       expect(streamer.is_active).toBe(1);
       expect(streamer.auto_record).toBe(1);
       expect(streamer.quality_preference).toBe('best');
+      expect(streamer.record_schedule).toBeNull();
       expect(streamer.id).toBeDefined();
       expect(streamer.created_at).toBeDefined();
       expect(streamer.updated_at).toBeDefined();
@@ -65,6 +66,14 @@ describe('StreamerModel', () => {
       expect(streamer.quality_preference).toBe('720p');
     });
 
+    it('should accept a record_schedule', () => {
+      const streamer = StreamerModel.create({
+        username: 'testuser',
+        record_schedule: '20:00-22:00',
+      });
+      expect(streamer.record_schedule).toBe('20:00-22:00');
+    });
+
     it('should throw error for duplicate username', () => {
       StreamerModel.create({ username: 'testuser' });
       
@@ -192,6 +201,16 @@ describe('StreamerModel', () => {
       expect(updated?.quality_preference).toBe('720p60');
     });
 
+    it('should update and clear record_schedule', () => {
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const scheduled = StreamerModel.update(streamer.id, { record_schedule: '23:00-01:00' });
+      expect(scheduled?.record_schedule).toBe('23:00-01:00');
+
+      const cleared = StreamerModel.update(streamer.id, { record_schedule: null });
+      expect(cleared?.record_schedule).toBeNull();
+    });
+
     it('should update multiple fields at once', () => {
       const streamer = StreamerModel.create({ username: 'testuser' });
       const updated = StreamerModel.update(streamer.id, {
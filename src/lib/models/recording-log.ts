@@ -39,7 +39,7 @@ export const RecordingLogModel = {
   findAll(filters?: {
     recordingId?: number;
     streamerUsername?: string;
-    level?: string;
+    level?: string | string[];
     limit?: number;
   }): RecordingLog[] {
     let query = 'SELECT * FROM recording_logs WHERE 1=1';
@@ -55,9 +55,15 @@ export const RecordingLogModel = {
       params.push(filters.streamerUsername);
     }
 
-    if (filters?.level) {
+    const level = filters?.level;
+    if (Array.isArray(level)) {
+      if (level.length > 0) {
+        query += ` AND level IN (${level.map(() => '?').join(', ')})`;
+        params.push(...level);
+      }
+    } else if (level) {
       query += ' AND level = ?';
-      params.push(filters.level);
+      params.push(level);
     }
 
     query += ' ORDER BY created_at DESC';
@@ -24,6 +24,7 @@ export interface CreateRecordingInput {
 }
 
 export interface UpdateRecordingInput {
+  file_path?: string;
   stream_title?: string;
   stream_category?: string;
   file_size_bytes?: number;
@@ -143,6 +144,10 @@ export const RecordingModel = {
       sets.push('stream_category = ?');
       values.push(input.stream_category);
     }
+    if (input.file_path !== undefined) {
+      sets.push('file_path = ?');
+      values.push(input.file_path);
+    }
     if (input.file_size_bytes !== undefined) {
       sets.push('file_size_bytes = ?');
       values.push(input.file_size_bytes);
@@ -7,6 +7,15 @@ export interface Settings {
   max_total_recordings_mb: number;
   max_recording_duration_hours: number;
   check_interval_seconds: number;
+  stall_timeout_seconds: number;
+  notify_webhook_url: string | null;
+  quality_in_filename: boolean;
+  rotate_daily: boolean;
+  rotate_on_title_change: boolean;
+  per_streamer_dirs: boolean;
+  record_chat: boolean;
+  metadata_sidecar: boolean;
+  allow_audio_only: boolean;
   updated_at: string;
 }
 
@@ -16,6 +25,15 @@ export interface UpdateSettingsInput {
   max_total_recordings_mb?: number;
   max_recording_duration_hours?: number;
   check_interval_seconds?: number;
+  stall_timeout_seconds?: number;
+  notify_webhook_url?: string | null;
+  quality_in_filename?: boolean;
+  rotate_daily?: boolean;
+  rotate_on_title_change?: boolean;
+  per_streamer_dirs?: boolean;
+  record_chat?: boolean;
+  metadata_sidecar?: boolean;
+  allow_audio_only?: boolean;
 }
 
 export const SettingsModel = {
@@ -50,6 +68,42 @@ export const SettingsModel = {
       sets.push('check_interval_seconds = ?');
       values.push(updates.check_interval_seconds);
     }
+    if (updates.stall_timeout_seconds !== undefined) {
+      sets.push('stall_timeout_seconds = ?');
+      values.push(updates.stall_timeout_seconds);
+    }
+    if (updates.notify_webhook_url !== undefined) {
+      sets.push('notify_webhook_url = ?');
+      values.push(updates.notify_webhook_url);
+    }
+    if (updates.quality_in_filename !== undefined) {
+      sets.push('quality_in_filename = ?');
+      values.push(updates.quality_in_filename ? 1 : 0);
+    }
+    if (updates.rotate_daily !== undefined) {
+      sets.push('rotate_daily = ?');
+      values.push(updates.rotate_daily ? 1 : 0);
+    }
+    if (updates.rotate_on_title_change !== undefined) {
+      sets.push('rotate_on_title_change = ?');
+      values.push(updates.rotate_on_title_change ? 1 : 0);
+    }
+    if (updates.per_streamer_dirs !== undefined) {
+      sets.push('per_streamer_dirs = ?');
+      values.push(updates.per_streamer_dirs ? 1 : 0);
+    }
+    if (updates.record_chat !== undefined) {
+      sets.push('record_chat = ?');
+      values.push(updates.record_chat ? 1 : 0);
+    }
+    if (updates.metadata_sidecar !== undefined) {
+      sets.push('metadata_sidecar = ?');
+      values.push(updates.metadata_sidecar ? 1 : 0);
+    }
+    if (updates.allow_audio_only !== undefined) {
+      sets.push('allow_audio_only = ?');
+      values.push(updates.allow_audio_only ? 1 : 0);
+    }
 
     if (sets.length === 0) return this.get();
 
@@ -8,6 +8,7 @@ export interface Streamer {
   is_active: boolean;
   auto_record: boolean;
   quality_preference: string;
+  record_schedule: string | null;
   created_at: string;
   updated_at: string;
 }
@@ -18,6 +19,7 @@ export interface CreateStreamerInput {
   avatar_url?: string;
   auto_record?: boolean;
   quality_preference?: string;
+  record_schedule?: string | null;
 }
 
 export interface UpdateStreamerInput {
@@ -26,14 +28,15 @@ export interface UpdateStreamerInput {
   is_active?: boolean;
   auto_record?: boolean;
   quality_preference?: string;
+  record_schedule?: string | null;
 }
 
 export const StreamerModel = {
   // Create a new streamer
   create(input: CreateStreamerInput): Streamer {
     const stmt = db.prepare(`
-      INSERT INTO streamers (username, display_name, avatar_url, auto_record, quality_preference)
-      VALUES (?, ?, ?, ?, ?)
+      INSERT INTO streamers (username, display_name, avatar_url, auto_record, quality_preference, record_schedule)
+      VALUES (?, ?, ?, ?, ?, ?)
       RETURNING *
     `);
 
@@ -42,7 +45,8 @@ export const StreamerModel = {
       input.display_name || input.username,
       input.avatar_url || null,
       (input.auto_record ?? true) ? 1 : 0,
-      input.quality_preference || 'best'
+      input.quality_preference || 'best',
+      input.record_schedule || null
     ) as Streamer;
 
     return result;
@@ -95,6 +99,10 @@ export const StreamerModel = {
       sets.push('quality_preference = ?');
       values.push(input.quality_preference);
     }
+    if (input.record_schedule !== undefined) {
+      sets.push('record_schedule = ?');
+      values.push(input.record_schedule || null);
+    }
 
     if (sets.length === 0) return this.findById(id);
 
@@ -0,0 +1,122 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { EventEmitter } from 'events';
+import tls from 'tls';
+import fs from 'fs';
+import { ChatRecorder } from '../chat-recorder';
+
+type MockSocket = EventEmitter & {
+  write: ReturnType<typeof vi.fn>;
+  setEncoding: ReturnType<typeof vi.fn>;
+  destroy: ReturnType<typeof vi.fn>;
+};
+
+let sockets: MockSocket[] = [];
+
+function createMockSocket(): MockSocket {
+  const socket = new EventEmitter() as MockSocket;
+  socket.write = vi.fn();
+  socket.setEncoding = vi.fn();
+  socket.destroy = vi.fn(() => {
+    socket.emit('close');
+  });
+  sockets.push(socket);
+  return socket;
+}
+
+const mockOutput = {
+  write: vi.fn(),
+  end: vi.fn(),
+  on: vi.fn(),
+};
+
+vi.mock('tls', () => ({
+  default: {
+    connect: vi.fn(() => createMockSocket()),
+  },
+}));
+
+vi.mock('fs', () => ({
+  default: {
+    createWriteStream: vi.fn(() => mockOutput),
+  },
+}));
+
+describe('ChatRecorder', () => {
+  beforeEach(() => {
+    sockets = [];
+    vi.mocked(tls.connect).mockClear();
+    mockOutput.write.mockClear();
+    mockOutput.end.mockClear();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it('should join the channel anonymously once connected', () => {
+    const recorder = new ChatRecorder('TestUser', '/recordings/testuser.chat.txt');
+    recorder.start();
+
+    expect(fs.createWriteStream).toHaveBeenCalledWith('/recordings/testuser.chat.txt', { flags: 'a' });
+    expect(tls.connect).toHaveBeenCalledWith(expect.objectContaining({ host: 'irc.chat.twitch.tv', port: 6697 }));
+
+    sockets[0].emit('secureConnect');
+
+    expect(sockets[0].write).toHaveBeenCalledWith(expect.stringMatching(/^NICK justinfan\d+\r\n$/));
+    expect(sockets[0].write).toHaveBeenCalledWith('JOIN #testuser\r\n');
+
+    recorder.stop();
+  });
+
+  it('should write chat messages, including ones split across chunks', () => {
+    const recorder = new ChatRecorder('testuser', '/recordings/testuser.chat.txt');
+    recorder.start();
+
+    sockets[0].emit('data', ':viewer1!viewer1@viewer1.tmi.twitch.tv PRIVMSG #testuser :hello\r\n:viewer2!viewer2@viewer2.tmi');
+    sockets[0].emit('data', '.twitch.tv PRIVMSG #testuser :hi there\r\n:tmi.twitch.tv 001 justinfan1 :Welcome\r\n');
+
+    expect(mockOutput.write).toHaveBeenCalledTimes(2);
+    expect(mockOutput.write.mock.calls[0][0]).toMatch(/^\[.*\] viewer1: hello\n$/);
+    expect(mockOutput.write.mock.calls[1][0]).toMatch(/^\[.*\] viewer2: hi there\n$/);
+
+    recorder.stop();
+  });
+
+  it('should answer server pings', () => {
+    const recorder = new ChatRecorder('testuser', '/recordings/testuser.chat.txt');
+    recorder.start();
+
+    sockets[0].emit('data', 'PING :tmi.twitch.tv\r\n');
+
+    expect(sockets[0].write).toHaveBeenCalledWith('PONG :tmi.twitch.tv\r\n');
+
+    recorder.stop();
+  });
+
+  it('should reconnect after a disconnect', () => {
+    vi.useFakeTimers();
+    const recorder = new ChatRecorder('testuser', '/recordings/testuser.chat.txt');
+    recorder.start();
+
+    sockets[0].emit('error', new Error('connection reset'));
+    sockets[0].emit('close');
+    vi.advanceTimersByTime(5000);
+
+    expect(tls.connect).toHaveBeenCalledTimes(2);
+
+    recorder.stop();
+  });
+
+  it('should close the connection and file when stopped', () => {
+    vi.useFakeTimers();
+    const recorder = new ChatRecorder('testuser', '/recordings/testuser.chat.txt');
+    recorder.start();
+
+    recorder.stop();
+    vi.advanceTimersByTime(10000);
+
+    expect(sockets[0].destroy).toHaveBeenCalled();
+    expect(mockOutput.end).toHaveBeenCalled();
+    expect(tls.connect).toHaveBeenCalledTimes(1);
+  });
+});
@@ -6,11 +6,15 @@ import { StreamerModel } from '@/lib/models/streamer';
 import { RecordingModel } from '@/lib/models/recording';
 import { RecordingLogModel } from '@/lib/models/recording-log';
 import { StatsModel } from '@/lib/models/stats';
+import { SettingsModel } from '@/lib/models/settings';
 import * as diskSpace from '@/lib/utils/disk-space';
+import fs from 'fs';
+import { ChatRecorder } from '../chat-recorder';
 
 // Mock disk-space module
 vi.mock('@/lib/utils/disk-space', () => ({
   checkDiskSpaceForRecording: vi.fn(() => ({ allowed: true, freeSpaceMb: 10000, usedPercentage: 50 })),
+  checkDiskSpaceDuringRecording: vi.fn(() => ({ allowed: true, freeSpaceMb: 10000, usedPercentage: 50 })),
   getDiskSpaceStatus: vi.fn(() => ({ total: '100 GB', used: '50 GB', free: '50 GB', usedPercentage: 50, status: 'ok' as const })),
   getTotalRecordingsSizeMb: vi.fn(() => 0),
   formatBytes: vi.fn((bytes: number) => `${bytes} B`),
@@ -25,12 +29,23 @@ vi.mock('child_process', () => ({
   spawn: vi.fn((...args: unknown[]) => mockSpawnImplementation(...args)),
 }));
 
+// Mock chat recorder
+const mockChatRecorder = { start: vi.fn(), stop: vi.fn() };
+vi.mock('../chat-recorder', () => ({
+  ChatRecorder: vi.fn(function () {
+    return mockChatRecorder;
+  }),
+}));
+
 // Mock fs
 vi.mock('fs', () => ({
   default: {
-    existsSync: vi.fn(() => true),
+    // Directories exist; recording files don't, so new filenames are free
+    existsSync: vi.fn((p: string) => !String(p).endsWith('.mp4')),
     mkdirSync: vi.fn(),
-    statSync: vi.fn(() => ({ size: 1024 * 1024 })),
+    renameSync: vi.fn(),
+    writeFileSync: vi.fn(),
+    statSync: vi.fn(() => ({ size: 1024 * 1024, isDirectory: () => true })),
     statfsSync: vi.fn(() => ({
       bsize: 4096,
       blocks: 10000000,
@@ -53,6 +68,9 @@ function createMockProcess(options: {
   exitCode?: number | null;
   delayMs?: number;
   longRunning?: boolean;
+  ignoreSigterm?: boolean;
+  // Stream names listed in the --json output, as streamlink does without a stream argument
+  streams?: string[];
 } = {}) {
   const {
     isLive = true,
@@ -62,10 +80,14 @@ function createMockProcess(options: {
     exitCode = 0,
     delayMs = 10,
     longRunning = false,
+    ignoreSigterm = false,
+    streams,
   } = options;
 
   const mockProcess = new EventEmitter() as EventEmitter & {
     killed: boolean;
+    exitCode: number | null;
+    signalCode: string | null;
     kill: (signal?: string) => boolean;
     stdout: EventEmitter;
     stderr: EventEmitter;
@@ -73,15 +95,24 @@ function createMockProcess(options: {
   };
 
   mockProcess.killed = false;
+  mockProcess.exitCode = null;
+  mockProcess.signalCode = null;
   mockProcess.stdout = new EventEmitter();
   mockProcess.stderr = new EventEmitter();
 
   // Keep track of timers to clean them up
   const timers: NodeJS.Timeout[] = [];
 
+  // A hung process that only SIGKILL can stop
+  let sigtermIgnored = false;
+
   mockProcess.kill = vi.fn((signal?: string) => {
-    if (mockProcess.killed) return true;
+    if (mockProcess.killed && !(sigtermIgnored && signal === 'SIGKILL')) return true;
     mockProcess.killed = true;
+    if (ignoreSigterm && signal !== 'SIGKILL') {
+      sigtermIgnored = true;
+      return true;
+    }
     
     const killDelay = signal === 'SIGKILL' ? 10 : delayMs;
     const timer = setTimeout(() => {
@@ -95,7 +126,7 @@ function createMockProcess(options: {
   // Method to force close the process (for test cleanup)
   mockProcess._forceClose = () => {
     timers.forEach(t => clearTimeout(t));
-    if (!mockProcess.killed) {
+    if (!mockProcess.killed || sigtermIgnored) {
       mockProcess.killed = true;
       mockProcess.emit('close', exitCode);
     }
@@ -114,6 +145,7 @@ function createMockProcess(options: {
         metadata: { title, category },
         type: 'hls',
         url: 'https://test-stream.example/stream.m3u8',
+        ...(streams && { streams: Object.fromEntries(streams.map(name => [name, {}])) }),
       });
     } else {
       output = JSON.stringify({ error: 'No playable streams found' });
@@ -140,6 +172,12 @@ function createMockProcess(options: {
   const originalEmit = mockProcess.emit.bind(mockProcess);
   mockProcess.emit = function(event: string | symbol, ...args: unknown[]) {
     if (event === 'close') {
+      if (mockProcess.exitCode !== null || mockProcess.signalCode !== null) return false;
+      if (args[0] === null) {
+        mockProcess.signalCode = 'SIGTERM';
+      } else {
+        mockProcess.exitCode = args[0] as number;
+      }
       activeMockProcesses.delete(mockProcess);
       timers.forEach(t => clearTimeout(t));
     }
@@ -410,6 +448,85 @@ describe('RecordingService', () => {
         );
       });
 
+      it('should not reuse the filename of an existing recording', async () => {
+        // The first candidate filename is already taken
+        vi.mocked(fs.existsSync).mockReturnValueOnce(true);
+
+        const streamer = StreamerModel.create({ username: 'testuser' });
+        const recordingId = await service.startRecording(streamer.id);
+
+        const recording = RecordingModel.findById(recordingId);
+        expect(recording?.file_path).toMatch(/^recordings\/testuser_.*_1\.mp4$/);
+        expect(mockSpawnImplementation).toHaveBeenCalledWith(
+          'streamlink',
+          expect.arrayContaining([expect.stringMatching(/testuser_.*_1\.mp4$/)])
+        );
+      });
+
+      it('should record into a per-streamer subdirectory when enabled', async () => {
+        SettingsModel.update({ per_streamer_dirs: true });
+
+        const streamer = StreamerModel.create({ username: 'testuser' });
+        const recordingId = await service.startRecording(streamer.id);
+
+        expect(fs.mkdirSync).toHaveBeenCalledWith(expect.stringMatching(/testuser$/), { recursive: true });
+        expect(RecordingModel.findById(recordingId)?.file_path).toMatch(/^recordings\/testuser\/testuser_.*\.mp4$/);
+      });
+
+      it('should fail clearly when the recordings path is not a directory', async () => {
+        vi.mocked(fs.statSync).mockReturnValueOnce({ size: 0, isDirectory: () => false } as unknown as fs.Stats);
+
+        const streamer = StreamerModel.create({ username: 'testuser' });
+
+        await expect(service.startRecording(streamer.id)).rejects.toThrow('exists but is not a directory');
+        expect(mockSpawnImplementation).not.toHaveBeenCalled();
+
+        const logs = RecordingLogModel.findAll({ streamerUsername: 'testuser' });
+        expect(logs.some(log => log.level === 'error' && log.message.includes('not a directory'))).toBe(true);
+      });
+
+      it('should create a missing recordings directory', async () => {
+        vi.mocked(fs.statSync).mockImplementationOnce(() => {
+          throw Object.assign(new Error('ENOENT: no such file or directory'), { code: 'ENOENT' });
+        });
+        vi.mocked(fs.mkdirSync).mockClear();
+
+        const streamer = StreamerModel.create({ username: 'testuser' });
+        await service.startRecording(streamer.id);
+
+        expect(fs.mkdirSync).toHaveBeenCalledWith(expect.any(String), { recursive: true });
+        expect(service.isRecording(streamer.id)).toBe(true);
+      });
+
+      it('should pass STREAMLINK_ARGS to every streamlink call', async () => {
+        process.env.STREAMLINK_ARGS = '--http-proxy http://proxy:8080 --http-no-ssl-verify';
+
+        try {
+          const streamer = StreamerModel.create({ username: 'testuser' });
+          await service.startRecording(streamer.id);
+
+          const extraArgs = ['--http-proxy', 'http://proxy:8080', '--http-no-ssl-verify'];
+          expect(mockSpawnImplementation).toHaveBeenCalledWith(
+            'streamlink',
+            ['--json', ...extraArgs, 'https://twitch.tv/testuser', 'best']
+          );
+          expect(mockSpawnImplementation).toHaveBeenCalledWith(
+            'streamlink',
+            [
+              '--twitch-disable-ads',
+              '--twitch-low-latency',
+              ...extraArgs,
+              '-o',
+              expect.stringContaining('testuser'),
+              'https://twitch.tv/testuser',
+              'best',
+            ]
+          );
+        } finally {
+          delete process.env.STREAMLINK_ARGS;
+        }
+      });
+
       it('should emit recordingStarted event', async () => {
         mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
           const isMetadataCheck = args.includes('--json');
@@ -567,6 +684,40 @@ describe('RecordingService', () => {
         expect(active[0].startTime).toBeInstanceOf(Date);
         expect(active[0].filePath).toBeDefined();
       });
+
+      it('should report the quality streamlink actually opened', async () => {
+        const streamer = StreamerModel.create({ username: 'testuser', quality_preference: 'best' });
+
+        const recordingId = await service.startRecording(streamer.id);
+
+        const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+        const recordingProcess = mockSpawnImplementation.mock.results[recordingCall].value;
+        recordingProcess.stdout.emit('data', Buffer.from('[cli][info] Opening stream: 1080p60 (hls)\n'));
+
+        expect(service.getActiveRecordings()[0].quality).toBe('1080p60');
+        expect(RecordingModel.findById(recordingId)?.quality).toBe('1080p60');
+      });
+    });
+
+    describe('getRecordingHealth', () => {
+      it('should report time since the file last grew', async () => {
+        vi.useFakeTimers();
+
+        const streamer = StreamerModel.create({ username: 'testuser' });
+
+        const startPromise = service.startRecording(streamer.id);
+        await vi.advanceTimersByTimeAsync(50);
+        const recordingId = await startPromise;
+
+        // File size is constant in the fs mock, so it only grows on the first check
+        await vi.advanceTimersByTimeAsync(20000);
+
+        const [health] = service.getRecordingHealth();
+        expect(health).toMatchObject({ recordingId, streamerId: streamer.id, username: 'testuser' });
+        expect(health.secondsSinceLastData).toBe(15);
+
+        vi.useRealTimers();
+      });
     });
 
     describe('getActiveCount', () => {
@@ -678,6 +829,31 @@ describe('RecordingService', () => {
         
         expect(service.getActiveCount()).toBe(1);
       });
+
+      it('should check multiple streamers with bounded concurrency', async () => {
+        let probesInFlight = 0;
+        let maxProbesInFlight = 0;
+        mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+          const isMetadataCheck = args.includes('--json');
+          const proc = createMockProcess({ isLive: true, delayMs: 20, longRunning: !isMetadataCheck });
+          if (isMetadataCheck) {
+            probesInFlight++;
+            maxProbesInFlight = Math.max(maxProbesInFlight, probesInFlight);
+            proc.on('close', () => probesInFlight--);
+          }
+          return proc;
+        });
+
+        const streamers = Array.from({ length: 6 }, (_, i) => StreamerModel.create({ username: `testuser${i}` }));
+
+        await service.checkAndRecordStreamers();
+
+        expect(service.getActiveCount()).toBe(6);
+        for (const streamer of streamers) {
+          expect(service.isRecording(streamer.id)).toBe(true);
+        }
+        expect(maxProbesInFlight).toBeLessThanOrEqual(4);
+      });
     });
   });
 
@@ -762,6 +938,37 @@ describe('RecordingService', () => {
       
       expect(service.getActiveCount()).toBe(0);
     });
+
+    it('should only post-process a recording after its process has exited', async () => {
+      vi.useFakeTimers();
+      SettingsModel.update({ metadata_sidecar: true });
+      vi.mocked(fs.writeFileSync).mockClear();
+      mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+        const isMetadataCheck = args.includes('--json');
+        return createMockProcess({ isLive: true, delayMs: 10, longRunning: !isMetadataCheck, ignoreSigterm: !isMetadataCheck });
+      });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      await startPromise;
+      const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+      const hungProcess = mockSpawnImplementation.mock.results[recordingCall].value;
+
+      const shutdownPromise = service.shutdown();
+
+      // SIGTERM is ignored, so the file is still being written
+      await vi.advanceTimersByTimeAsync(1900);
+      expect(fs.writeFileSync).not.toHaveBeenCalled();
+
+      // Force kill at 2s; post-processing runs once the process has closed
+      await vi.advanceTimersByTimeAsync(200);
+      await shutdownPromise;
+      expect(hungProcess.kill).toHaveBeenCalledWith('SIGKILL');
+      expect(fs.writeFileSync).toHaveBeenCalled();
+
+      vi.useRealTimers();
+    });
   });
 
   describe('Utility Methods', () => {
@@ -770,19 +977,33 @@ describe('RecordingService', () => {
         expect(service.getTotalDownloadSpeed()).toBe('0 MB/s');
       });
 
-      it('should return estimated speed for active recordings', async () => {
-        mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
-          const isMetadataCheck = args.includes('--json');
-          return createMockProcess({ isLive: true, delayMs: 10, longRunning: !isMetadataCheck });
-        });
-        
+      it('should sum the measured file growth of active recordings', async () => {
+        vi.useFakeTimers();
+        const defaultStat = vi.mocked(fs.statSync).getMockImplementation()!;
+        // Each recording's file grows by 15MB per 5s size check, i.e. 3 MB/s
+        const sizes = new Map<string, number>();
+        vi.mocked(fs.statSync).mockImplementation(((p: string) => {
+          const size = (sizes.get(p) ?? 0) + 15 * 1024 * 1024;
+          sizes.set(p, size);
+          return { size, isDirectory: () => true };
+        }) as unknown as typeof fs.statSync);
+
         const streamer1 = StreamerModel.create({ username: 'user1' });
         const streamer2 = StreamerModel.create({ username: 'user2' });
-        
-        await service.startRecording(streamer1.id);
-        await service.startRecording(streamer2.id);
-        
-        expect(service.getTotalDownloadSpeed()).toBe('~12 MB/s');
+
+        const start1 = service.startRecording(streamer1.id);
+        const start2 = service.startRecording(streamer2.id);
+        await vi.advanceTimersByTimeAsync(50);
+        await Promise.all([start1, start2]);
+
+        // No measurement until two size checks have run
+        expect(service.getTotalDownloadSpeed()).toBe('0.0 MB/s');
+
+        await vi.advanceTimersByTimeAsync(10000);
+        expect(service.getTotalDownloadSpeed()).toBe('6.0 MB/s');
+
+        vi.mocked(fs.statSync).mockImplementation(defaultStat);
+        vi.useRealTimers();
       });
     });
 
@@ -848,6 +1069,850 @@ describe('RecordingService', () => {
       // Should not have started recording due to disk space check
       expect(service.getActiveCount()).toBe(0);
     });
+
+    it('should stop an active recording when free space drops below the minimum', async () => {
+      vi.useFakeTimers();
+      const mockedDiskSpace = vi.mocked(diskSpace);
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      const recordingId = await startPromise;
+
+      mockedDiskSpace.checkDiskSpaceDuringRecording.mockReturnValueOnce({
+        allowed: false,
+        reason: 'Disk space low. Free: 100MB, Minimum: 5000MB',
+        freeSpaceMb: 100,
+        usedPercentage: 99,
+      });
+
+      // Next file size check sees the low disk space
+      await vi.advanceTimersByTimeAsync(5100);
+
+      expect(service.isRecording(streamer.id)).toBe(false);
+      const logs = RecordingLogModel.findAll({ recordingId });
+      expect(logs.some(l => l.message.includes('Recording stopped: Disk space low'))).toBe(true);
+
+      vi.useRealTimers();
+    });
+  });
+
+  describe('Stall Watchdog', () => {
+    it('should restart a recording whose file stops growing', async () => {
+      vi.useFakeTimers();
+      SettingsModel.update({ stall_timeout_seconds: 30 });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      const firstRecordingId = await startPromise;
+
+      // File size is constant in the fs mock, so it only grows on the first check
+      await vi.advanceTimersByTimeAsync(36000);
+
+      const logs = RecordingLogModel.findAll({ recordingId: firstRecordingId });
+      expect(logs.some(l => l.message.includes('Recording stalled'))).toBe(true);
+
+      const recordings = RecordingModel.findAll();
+      expect(recordings).toHaveLength(2);
+      expect(service.isRecording(streamer.id)).toBe(true);
+      expect(service.getActiveRecordings()[0].recordingId).not.toBe(firstRecordingId);
+
+      vi.useRealTimers();
+    });
+
+    it('should force kill a stalled process that ignores SIGTERM and restart', async () => {
+      vi.useFakeTimers();
+      SettingsModel.update({ stall_timeout_seconds: 30 });
+      mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+        const isMetadataCheck = args.includes('--json');
+        return createMockProcess({ isLive: true, delayMs: 10, longRunning: !isMetadataCheck, ignoreSigterm: !isMetadataCheck });
+      });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      const firstRecordingId = await startPromise;
+      const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+      const hungProcess = mockSpawnImplementation.mock.results[recordingCall].value;
+
+      // Stall detected at ~35s; SIGTERM is ignored, so nothing restarts yet
+      await vi.advanceTimersByTimeAsync(36000);
+      expect(hungProcess.kill).toHaveBeenCalledWith('SIGTERM');
+      expect(RecordingModel.findAll()).toHaveLength(1);
+
+      // SIGKILL lands 5s later and the recording restarts
+      await vi.advanceTimersByTimeAsync(5100);
+      expect(hungProcess.kill).toHaveBeenCalledWith('SIGKILL');
+      expect(RecordingModel.findById(firstRecordingId)?.status).not.toBe('recording');
+      expect(RecordingModel.findAll()).toHaveLength(2);
+      expect(service.isRecording(streamer.id)).toBe(true);
+
+      vi.useRealTimers();
+    });
+
+    it('should not restart when the watchdog is disabled', async () => {
+      vi.useFakeTimers();
+      SettingsModel.update({ stall_timeout_seconds: 0 });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      const recordingId = await startPromise;
+
+      await vi.advanceTimersByTimeAsync(600000);
+
+      expect(RecordingModel.findAll()).toHaveLength(1);
+      expect(service.getActiveRecordings()[0].recordingId).toBe(recordingId);
+
+      vi.useRealTimers();
+    });
+  });
+
+  describe('Daily Rotation', () => {
+    it('should start a new recording when the local date changes', async () => {
+      vi.useFakeTimers();
+      vi.setSystemTime(new Date(2026, 0, 1, 23, 59, 50));
+      SettingsModel.update({ rotate_daily: true });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      const firstRecordingId = await startPromise;
+
+      // Second file size check lands just after midnight
+      await vi.advanceTimersByTimeAsync(15000);
+
+      const logs = RecordingLogModel.findAll({ recordingId: firstRecordingId });
+      expect(logs.some(l => l.message.includes('Date changed'))).toBe(true);
+
+      expect(RecordingModel.findAll()).toHaveLength(2);
+      expect(service.isRecording(streamer.id)).toBe(true);
+      expect(service.getActiveRecordings()[0].recordingId).not.toBe(firstRecordingId);
+
+      // The stream is known to be live, so the rotation doesn't probe it again
+      const probes = mockSpawnImplementation.mock.calls.filter(call => call[1].includes('--json'));
+      expect(probes).toHaveLength(1);
+      expect(RecordingModel.findAll().every(r => r.stream_title === 'Test Stream')).toBe(true);
+
+      vi.useRealTimers();
+    });
+
+    it('should keep recording across midnight when disabled', async () => {
+      vi.useFakeTimers();
+      vi.setSystemTime(new Date(2026, 0, 1, 23, 59, 50));
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      const recordingId = await startPromise;
+
+      await vi.advanceTimersByTimeAsync(15000);
+
+      expect(RecordingModel.findAll()).toHaveLength(1);
+      expect(service.getActiveRecordings()[0].recordingId).toBe(recordingId);
+
+      vi.useRealTimers();
+    });
+  });
+
+  describe('Recording Schedule', () => {
+    it('should not auto-record outside the schedule window', async () => {
+      vi.useFakeTimers();
+      vi.setSystemTime(new Date(2026, 0, 1, 12, 0, 0));
+
+      const streamer = StreamerModel.create({ username: 'testuser', record_schedule: '20:00-22:00' });
+
+      const checkPromise = service.checkAndRecordStreamers();
+      await vi.advanceTimersByTimeAsync(100);
+      await checkPromise;
+
+      expect(mockSpawnImplementation).not.toHaveBeenCalled();
+      expect(service.isRecording(streamer.id)).toBe(false);
+
+      vi.useRealTimers();
+    });
+
+    it('should stop the recording when the schedule window ends', async () => {
+      vi.useFakeTimers();
+      vi.setSystemTime(new Date(2026, 0, 1, 21, 59, 50));
+
+      const streamer = StreamerModel.create({ username: 'testuser', record_schedule: '20:00-22:00' });
+
+      const checkPromise = service.checkAndRecordStreamers();
+      await vi.advanceTimersByTimeAsync(100);
+      await checkPromise;
+      expect(service.isRecording(streamer.id)).toBe(true);
+      const recordingId = service.getActiveRecordings()[0].recordingId;
+
+      // Second file size check lands just after 22:00
+      await vi.advanceTimersByTimeAsync(15000);
+
+      const logs = RecordingLogModel.findAll({ recordingId });
+      expect(logs.some(l => l.message.includes('schedule window ended'))).toBe(true);
+      expect(service.isRecording(streamer.id)).toBe(false);
+      expect(RecordingModel.findAll()).toHaveLength(1);
+
+      vi.useRealTimers();
+    });
+
+    it('should stop a recording at its requested stop time', async () => {
+      vi.useFakeTimers();
+      vi.setSystemTime(new Date(2026, 0, 1, 21, 59, 50));
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id, { stopAt: new Date(2026, 0, 1, 22, 0, 0) });
+      await vi.advanceTimersByTimeAsync(50);
+      const recordingId = await startPromise;
+
+      // Second file size check lands just after 22:00
+      await vi.advanceTimersByTimeAsync(15000);
+
+      const logs = RecordingLogModel.findAll({ recordingId });
+      expect(logs.some(l => l.message.includes('reached the requested stop time'))).toBe(true);
+      expect(service.isRecording(streamer.id)).toBe(false);
+      expect(RecordingModel.findAll()).toHaveLength(1);
+
+      vi.useRealTimers();
+    });
+
+    it('should not restart a stalled recording after the schedule window ends', async () => {
+      vi.useFakeTimers();
+      vi.setSystemTime(new Date(2026, 0, 1, 21, 59, 20));
+      SettingsModel.update({ stall_timeout_seconds: 30 });
+      // The stall is detected before the window ends, but the process only exits after it
+      mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+        const isMetadataCheck = args.includes('--json');
+        return createMockProcess({ isLive: true, delayMs: 10, longRunning: !isMetadataCheck, ignoreSigterm: !isMetadataCheck });
+      });
+
+      const streamer = StreamerModel.create({ username: 'testuser', record_schedule: '20:00-22:00' });
+
+      const checkPromise = service.checkAndRecordStreamers();
+      await vi.advanceTimersByTimeAsync(100);
+      await checkPromise;
+      const recordingId = service.getActiveRecordings()[0].recordingId;
+
+      // Stall detected at ~21:59:55; SIGKILL lands after 22:00
+      await vi.advanceTimersByTimeAsync(36000);
+      expect(RecordingModel.findById(recordingId)?.status).toBe('recording');
+      await vi.advanceTimersByTimeAsync(5100);
+
+      const logs = RecordingLogModel.findAll({ streamerUsername: 'testuser' });
+      expect(logs.some(l => l.message.includes('Recording stalled'))).toBe(true);
+      expect(logs.some(l => l.message.includes('not restarting recording'))).toBe(true);
+      expect(service.isRecording(streamer.id)).toBe(false);
+      expect(RecordingModel.findAll()).toHaveLength(1);
+
+      vi.useRealTimers();
+    });
+  });
+
+  describe('Title Change Rotation', () => {
+    it('should start a new recording when the stream title changes', async () => {
+      vi.useFakeTimers();
+      SettingsModel.update({ rotate_on_title_change: true });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      const firstRecordingId = await startPromise;
+
+      mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+        const isMetadataCheck = args.includes('--json');
+        return createMockProcess({ isLive: true, title: 'New Topic', delayMs: 10, longRunning: !isMetadataCheck });
+      });
+
+      await vi.advanceTimersByTimeAsync(61000);
+
+      const logs = RecordingLogModel.findAll({ recordingId: firstRecordingId });
+      expect(logs.some(l => l.message.includes('Stream title changed to "New Topic"'))).toBe(true);
+
+      expect(RecordingModel.findAll()).toHaveLength(2);
+      const [current] = service.getActiveRecordings();
+      expect(current.recordingId).not.toBe(firstRecordingId);
+      expect(RecordingModel.findById(current.recordingId)?.stream_title).toBe('New Topic');
+
+      // The rotated file is named after the new title; the first one keeps the plain name
+      expect(RecordingModel.findById(current.recordingId)?.file_path).toMatch(/\/testuser_[^/]*_new-topic\.mp4$/);
+      expect(RecordingModel.findById(firstRecordingId)?.file_path).toMatch(/\/testuser_[^/_]*\.mp4$/);
+
+      // Only the title check probed; the new file started without re-probing the stream
+      const probes = mockSpawnImplementation.mock.calls.filter(call => call[1].includes('--json'));
+      expect(probes).toHaveLength(1);
+
+      vi.useRealTimers();
+    });
+
+    it('should keep recording while the title is unchanged', async () => {
+      vi.useFakeTimers();
+      SettingsModel.update({ rotate_on_title_change: true });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      const recordingId = await startPromise;
+
+      await vi.advanceTimersByTimeAsync(61000);
+
+      expect(RecordingModel.findAll()).toHaveLength(1);
+      expect(service.getActiveRecordings()[0].recordingId).toBe(recordingId);
+
+      vi.useRealTimers();
+    });
+  });
+
+  describe('Chat Recording', () => {
+    beforeEach(() => {
+      vi.mocked(ChatRecorder).mockClear();
+      mockChatRecorder.start.mockClear();
+      mockChatRecorder.stop.mockClear();
+    });
+
+    it('should record chat next to the video when enabled', async () => {
+      SettingsModel.update({ record_chat: true });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      await service.startRecording(streamer.id);
+
+      expect(ChatRecorder).toHaveBeenCalledWith('testuser', expect.stringMatching(/testuser_.*\.chat\.txt$/));
+      expect(mockChatRecorder.start).toHaveBeenCalled();
+
+      await service.stopRecording(streamer.id);
+
+      expect(mockChatRecorder.stop).toHaveBeenCalled();
+    });
+
+    it('should not record chat when disabled', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      await service.startRecording(streamer.id);
+
+      expect(ChatRecorder).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('Recording Outcome', () => {
+    it('should mark a recording that streamlink ends with an error as failed', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const recordingId = await service.startRecording(streamer.id);
+
+      const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+      const recordingProcess = mockSpawnImplementation.mock.results[recordingCall].value;
+      recordingProcess.stderr.emit(
+        'data',
+        Buffer.from('[stream.hls][warning] Retrying\nerror: Unable to open URL: https://example.test (read timeout)\n')
+      );
+      recordingProcess.emit('close', 1);
+
+      await new Promise(r => setTimeout(r, 50));
+
+      const recording = RecordingModel.findById(recordingId);
+      expect(recording?.status).toBe('error');
+      expect(recording?.error_message).toBe('error: Unable to open URL: https://example.test (read timeout)');
+    });
+
+    it('should fall back to the exit code when streamlink printed nothing', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const recordingId = await service.startRecording(streamer.id);
+
+      const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+      mockSpawnImplementation.mock.results[recordingCall].value.emit('close', 2);
+
+      await new Promise(r => setTimeout(r, 50));
+
+      expect(RecordingModel.findById(recordingId)?.error_message).toBe('streamlink exited with code 2');
+    });
+
+    it('should not treat a non-zero exit after a stop as a failure', async () => {
+      mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+        const isMetadataCheck = args.includes('--json');
+        return createMockProcess({ isLive: true, delayMs: 10, longRunning: !isMetadataCheck, exitCode: isMetadataCheck ? 0 : 1 });
+      });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const recordingId = await service.startRecording(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      await new Promise(r => setTimeout(r, 100));
+
+      const recording = RecordingModel.findById(recordingId);
+      expect(recording?.status).toBe('stopped');
+      expect(recording?.error_message).toBeNull();
+    });
+  });
+
+  describe('Post-recording Hook', () => {
+    afterEach(() => {
+      delete process.env.POST_RECORDING_COMMAND;
+    });
+
+    it('should run the configured command when a recording completes', async () => {
+      process.env.POST_RECORDING_COMMAND = 'upload {streamer} {file}';
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.startRecording(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(mockSpawnImplementation).toHaveBeenCalledWith(
+        expect.stringMatching(/^upload 'testuser' '.*testuser_.*\.mp4'$/),
+        [],
+        expect.objectContaining({ shell: true })
+      );
+    });
+
+    it('should not run the command when the recording fails', async () => {
+      process.env.POST_RECORDING_COMMAND = 'upload {file}';
+
+      // Recording process exits on its own with a non-zero code
+      mockSpawnImplementation = vi.fn(() => createMockProcess({ isLive: true, exitCode: 1 }));
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.startRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(service.isRecording(streamer.id)).toBe(false);
+      expect(mockSpawnImplementation).not.toHaveBeenCalledWith(
+        expect.any(String),
+        [],
+        expect.objectContaining({ shell: true })
+      );
+    });
+
+    it('should not run the command for a stalled recording', async () => {
+      vi.useFakeTimers();
+      process.env.POST_RECORDING_COMMAND = 'upload {file}';
+      SettingsModel.update({ stall_timeout_seconds: 30 });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      const startPromise = service.startRecording(streamer.id);
+      await vi.advanceTimersByTimeAsync(50);
+      await startPromise;
+
+      // Stall detected at ~35s; streamlink exits cleanly after SIGTERM and the recording restarts
+      await vi.advanceTimersByTimeAsync(36000);
+
+      expect(RecordingModel.findAll()).toHaveLength(2);
+      expect(mockSpawnImplementation).not.toHaveBeenCalledWith(
+        expect.any(String),
+        [],
+        expect.objectContaining({ shell: true })
+      );
+
+      vi.useRealTimers();
+    });
+
+    it('should not run the command for a rejected audio-only recording', async () => {
+      process.env.POST_RECORDING_COMMAND = 'upload {file}';
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.startRecording(streamer.id);
+      const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+      mockSpawnImplementation.mock.results[recordingCall].value.stdout.emit(
+        'data',
+        Buffer.from('[cli][info] Opening stream: audio_only (hls)\n')
+      );
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(service.isRecording(streamer.id)).toBe(false);
+      expect(mockSpawnImplementation).not.toHaveBeenCalledWith(
+        expect.any(String),
+        [],
+        expect.objectContaining({ shell: true })
+      );
+    });
+
+    it('should not run anything when no command is configured', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.startRecording(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(mockSpawnImplementation.mock.calls.every(call => call[0] === 'streamlink')).toBe(true);
+    });
+  });
+
+  describe('Webhook Notifications', () => {
+    let fetchMock: ReturnType<typeof vi.fn>;
+
+    beforeEach(() => {
+      fetchMock = vi.fn(async () => ({ ok: true, status: 200 }));
+      vi.stubGlobal('fetch', fetchMock);
+    });
+
+    afterEach(() => {
+      // Clear the URL so the outer shutdown doesn't hit the real fetch
+      SettingsModel.update({ notify_webhook_url: null });
+      vi.unstubAllGlobals();
+    });
+
+    const sentEvents = () =>
+      fetchMock.mock.calls.map(call => JSON.parse((call[1] as RequestInit).body as string));
+
+    it('should notify the webhook when a recording starts and ends', async () => {
+      SettingsModel.update({ notify_webhook_url: 'https://example.com/hook' });
+
+      const streamer = StreamerModel.create({ username: 'testuser', quality_preference: '720p60' });
+
+      await service.startRecording(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(fetchMock).toHaveBeenCalledTimes(2);
+      expect(fetchMock.mock.calls[0][0]).toBe('https://example.com/hook');
+
+      const [started, ended] = sentEvents();
+      expect(started).toMatchObject({ event: 'recording.started', streamer: 'testuser', quality: '720p60' });
+      expect(ended).toMatchObject({ event: 'recording.ended', streamer: 'testuser', fileSizeBytes: 1024 * 1024 });
+      expect(ended.file).toMatch(/^recordings\/testuser_[^/]*\.mp4$/);
+    });
+
+    it('should keep recording when the webhook fails', async () => {
+      SettingsModel.update({ notify_webhook_url: 'https://example.com/hook' });
+      fetchMock.mockRejectedValue(new Error('connection refused'));
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.startRecording(streamer.id);
+
+      // Let the failed notification settle
+      await new Promise(r => setTimeout(r, 10));
+
+      expect(service.isRecording(streamer.id)).toBe(true);
+      const logs = RecordingLogModel.findAll({ streamerUsername: 'testuser' });
+      expect(logs.some(log => log.message.includes('Failed to send recording.started webhook notification'))).toBe(true);
+    });
+
+    it('should not send anything when no webhook is configured', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.startRecording(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(fetchMock).not.toHaveBeenCalled();
+    });
+  });
+
+  describe('Quality in Filename', () => {
+    beforeEach(() => {
+      vi.mocked(fs.renameSync).mockClear();
+    });
+
+    const startAndOpenStream = async (streamerId: number) => {
+      const recordingId = await service.startRecording(streamerId);
+      const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+      mockSpawnImplementation.mock.results[recordingCall].value.stdout.emit(
+        'data',
+        Buffer.from('[cli][info] Opening stream: 1080p60 (hls)\n')
+      );
+      return recordingId;
+    };
+
+    it('should append the selected quality to the file name when enabled', async () => {
+      SettingsModel.update({ quality_in_filename: true });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const recordingId = await startAndOpenStream(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(fs.renameSync).toHaveBeenCalledWith(
+        expect.stringMatching(/testuser_.*\.mp4$/),
+        expect.stringMatching(/testuser_.*_1080p60\.mp4$/)
+      );
+      expect(RecordingModel.findById(recordingId)?.file_path).toMatch(/^recordings\/testuser_.*_1080p60\.mp4$/);
+    });
+
+    it('should not overwrite an existing file with the quality name', async () => {
+      SettingsModel.update({ quality_in_filename: true });
+      // An earlier recording already took the quality-suffixed name
+      vi.mocked(fs.existsSync).mockImplementation((p) => !String(p).endsWith('.mp4') || String(p).endsWith('_1080p60.mp4'));
+
+      try {
+        const streamer = StreamerModel.create({ username: 'testuser' });
+        const recordingId = await startAndOpenStream(streamer.id);
+        await service.stopRecording(streamer.id);
+
+        // Wait for process to end
+        await new Promise(r => setTimeout(r, 100));
+
+        expect(fs.renameSync).toHaveBeenCalledWith(
+          expect.stringMatching(/testuser_.*\.mp4$/),
+          expect.stringMatching(/testuser_.*_1080p60_1\.mp4$/)
+        );
+        expect(RecordingModel.findById(recordingId)?.file_path).toMatch(/_1080p60_1\.mp4$/);
+      } finally {
+        vi.mocked(fs.existsSync).mockImplementation((p) => !String(p).endsWith('.mp4'));
+      }
+    });
+
+    it('should rename the chat transcript along with the video', async () => {
+      SettingsModel.update({ quality_in_filename: true, record_chat: true });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      await startAndOpenStream(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(fs.renameSync).toHaveBeenCalledWith(
+        expect.stringMatching(/testuser_[^/]*\d{3}Z\.chat\.txt$/),
+        expect.stringMatching(/testuser_.*_1080p60\.chat\.txt$/)
+      );
+    });
+
+    it('should leave the file name alone when disabled', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const recordingId = await startAndOpenStream(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(fs.renameSync).not.toHaveBeenCalled();
+      expect(RecordingModel.findById(recordingId)?.file_path).not.toContain('1080p60');
+    });
+  });
+
+  describe('Missing Quality', () => {
+    const emitStderr = (output: string) => {
+      const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+      mockSpawnImplementation.mock.results[recordingCall].value.stderr.emit('data', Buffer.from(output));
+    };
+
+    it('should explain when a channel only offers its source quality', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser', quality_preference: '720p60' });
+      await service.startRecording(streamer.id);
+
+      emitStderr(
+        "error: The specified stream(s) '720p60' could not be found.\n" +
+        '       Available streams: audio_only, 1080p60 (worst, best)'
+      );
+
+      const logs = RecordingLogModel.findAll({ streamerUsername: 'testuser', level: 'error' });
+      expect(logs).toHaveLength(1);
+      expect(logs[0].message).toContain('only offers its source quality (1080p60)');
+      expect(logs[0].message).toContain('"720p60,best"');
+    });
+
+    it('should list the available qualities otherwise', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser', quality_preference: '900p' });
+      await service.startRecording(streamer.id);
+
+      emitStderr(
+        "error: The specified stream(s) '900p' could not be found.\n" +
+        '       Available streams: audio_only, 480p (worst), 720p60, 1080p60 (best)'
+      );
+
+      const logs = RecordingLogModel.findAll({ streamerUsername: 'testuser', level: 'error' });
+      expect(logs[0].message).toContain('Available: audio_only, 480p, 720p60, 1080p60.');
+    });
+
+    const emitAudioOnlyOpened = () => {
+      const recordingCall = mockSpawnImplementation.mock.calls.findIndex(call => call[1].includes('-o'));
+      const recordingProcess = mockSpawnImplementation.mock.results[recordingCall].value;
+      recordingProcess.stdout.emit('data', Buffer.from('[cli][info] Opening stream: audio_only (hls)\n'));
+      return recordingProcess;
+    };
+
+    it('should warn and keep recording audio only when allowed', async () => {
+      SettingsModel.update({ allow_audio_only: true });
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const recordingId = await service.startRecording(streamer.id);
+      const recordingProcess = emitAudioOnlyOpened();
+
+      const logs = RecordingLogModel.findAll({ recordingId, level: 'warn' });
+      expect(logs.some(l => l.message.includes('Only audio is available'))).toBe(true);
+      expect(RecordingModel.findById(recordingId)?.quality).toBe('audio_only');
+      expect(recordingProcess.kill).not.toHaveBeenCalled();
+      expect(service.isRecording(streamer.id)).toBe(true);
+    });
+
+    it('should stop with an error when only audio is available and audio-only is disabled', async () => {
+      SettingsModel.update({ quality_in_filename: true, metadata_sidecar: true });
+      vi.mocked(fs.renameSync).mockClear();
+      vi.mocked(fs.writeFileSync).mockClear();
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const recordingId = await service.startRecording(streamer.id);
+      const recordingProcess = emitAudioOnlyOpened();
+
+      const logs = RecordingLogModel.findAll({ recordingId, level: 'error' });
+      expect(logs.some(l => l.message.includes('audio-only recordings are disabled'))).toBe(true);
+      expect(recordingProcess.kill).toHaveBeenCalledWith('SIGTERM');
+
+      await new Promise(resolve => setTimeout(resolve, 50));
+      expect(service.isRecording(streamer.id)).toBe(false);
+
+      // Stored as a failed recording, without the finished-recording rename or sidecar
+      const recording = RecordingModel.findById(recordingId);
+      expect(recording?.status).toBe('error');
+      expect(recording?.error_message).toContain('audio-only recordings are disabled');
+      expect(fs.renameSync).not.toHaveBeenCalled();
+      expect(fs.writeFileSync).not.toHaveBeenCalled();
+
+      // The auto-checker doesn't start the same broadcast again
+      await service.checkAndRecordStreamers();
+      expect(RecordingModel.findAll()).toHaveLength(1);
+      expect(service.isRecording(streamer.id)).toBe(false);
+    });
+
+    it('should not auto-record a broadcast that only offers audio', async () => {
+      mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+        const isMetadataCheck = args.includes('--json');
+        return createMockProcess({
+          isLive: true,
+          delayMs: 10,
+          longRunning: !isMetadataCheck,
+          streams: ['audio_only', 'worst', 'best'],
+        });
+      });
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.checkAndRecordStreamers();
+      await service.checkAndRecordStreamers();
+
+      expect(mockSpawnImplementation.mock.calls.some(call => call[1].includes('-o'))).toBe(false);
+      expect(RecordingModel.findAll()).toHaveLength(0);
+      expect(service.isRecording(streamer.id)).toBe(false);
+
+      // Rejected once per broadcast: the second check only probes whether it is still live
+      const streamListProbes = mockSpawnImplementation.mock.calls.filter(call => !call[1].includes('best'));
+      expect(streamListProbes).toHaveLength(1);
+      const logs = RecordingLogModel.findAll({ streamerUsername: 'testuser', level: 'error' });
+      expect(logs.filter(l => l.message.includes('Recording skipped'))).toHaveLength(1);
+    });
+
+    it('should try again once an audio-only broadcast has gone offline', async () => {
+      let streams = ['audio_only', 'worst', 'best'];
+      let isLive = true;
+      mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+        const isMetadataCheck = args.includes('--json');
+        return createMockProcess({ isLive, delayMs: 10, longRunning: !isMetadataCheck, streams });
+      });
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.checkAndRecordStreamers();
+      expect(service.isRecording(streamer.id)).toBe(false);
+
+      isLive = false;
+      await service.checkAndRecordStreamers();
+
+      isLive = true;
+      streams = ['audio_only', '720p60', 'worst', 'best'];
+      await service.checkAndRecordStreamers();
+      expect(service.isRecording(streamer.id)).toBe(true);
+    });
+
+    it('should auto-record audio-only broadcasts when allowed', async () => {
+      SettingsModel.update({ allow_audio_only: true });
+      mockSpawnImplementation = vi.fn((command: string, args: string[]) => {
+        const isMetadataCheck = args.includes('--json');
+        return createMockProcess({
+          isLive: true,
+          delayMs: 10,
+          longRunning: !isMetadataCheck,
+          streams: ['audio_only', 'worst', 'best'],
+        });
+      });
+      const streamer = StreamerModel.create({ username: 'testuser' });
+
+      await service.checkAndRecordStreamers();
+
+      expect(service.isRecording(streamer.id)).toBe(true);
+    });
+
+    it('should keep recording when audio_only was requested', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser', quality_preference: 'audio_only' });
+      await service.startRecording(streamer.id);
+      const recordingProcess = emitAudioOnlyOpened();
+
+      expect(recordingProcess.kill).not.toHaveBeenCalled();
+      expect(RecordingLogModel.findAll({ streamerUsername: 'testuser', level: 'error' })).toHaveLength(0);
+    });
+  });
+
+  describe('Metadata Sidecar', () => {
+    beforeEach(() => {
+      vi.mocked(fs.writeFileSync).mockClear();
+      vi.mocked(fs.renameSync).mockClear();
+    });
+
+    it('should write a JSON sidecar atomically when a recording ends', async () => {
+      SettingsModel.update({ metadata_sidecar: true });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      const recordingId = await service.startRecording(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      const [tempPath, contents] = vi.mocked(fs.writeFileSync).mock.calls[0];
+      expect(tempPath).toMatch(/testuser_.*\.json\.tmp$/);
+      expect(fs.renameSync).toHaveBeenCalledWith(tempPath, String(tempPath).replace(/\.tmp$/, ''));
+
+      expect(JSON.parse(String(contents))).toMatchObject({
+        recordingId,
+        streamer: 'testuser',
+        title: 'Test Stream',
+        category: 'Just Chatting',
+        url: 'https://twitch.tv/testuser',
+        fileSizeBytes: 1024 * 1024,
+      });
+    });
+
+    it('should write a sidecar for recordings finalized on shutdown', async () => {
+      SettingsModel.update({ metadata_sidecar: true });
+
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      await service.startRecording(streamer.id);
+      await service.shutdown();
+
+      const [, contents] = vi.mocked(fs.writeFileSync).mock.calls[0];
+      expect(JSON.parse(String(contents))).toMatchObject({ streamer: 'testuser', status: 'completed' });
+    });
+
+    it('should not write a sidecar when disabled', async () => {
+      const streamer = StreamerModel.create({ username: 'testuser' });
+      await service.startRecording(streamer.id);
+      await service.stopRecording(streamer.id);
+
+      // Wait for process to end
+      await new Promise(r => setTimeout(r, 100));
+
+      expect(fs.writeFileSync).not.toHaveBeenCalled();
+    });
   });
 
   describe('Shutdown Race Condition', () => {
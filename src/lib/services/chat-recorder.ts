@@ -0,0 +1,96 @@
+import tls from 'tls';
+import fs from 'fs';
+import { formatChatLine, parseChatMessage } from '../utils/twitch-chat';
+
+const CHAT_HOST = 'irc.chat.twitch.tv';
+const CHAT_PORT = 6697;
+const RECONNECT_DELAY_MS = 5000;
+
+// Records a channel's chat to a text file over an anonymous, read-only IRC connection.
+// Connection problems are logged and retried; they never affect the video recording.
+export class ChatRecorder {
+  private channel: string;
+  readonly filePath: string;
+  private socket: tls.TLSSocket | null = null;
+  private output: fs.WriteStream | null = null;
+  private reconnectTimer: NodeJS.Timeout | null = null;
+  private buffer = '';
+  private stopped = false;
+
+  constructor(channel: string, filePath: string) {
+    this.channel = channel.toLowerCase();
+    this.filePath = filePath;
+  }
+
+  start(): void {
+    this.output = fs.createWriteStream(this.filePath, { flags: 'a' });
+    this.output.on('error', (error) => {
+      console.error(`Failed to write chat log for ${this.channel}:`, error);
+    });
+    this.connect();
+  }
+
+  stop(): void {
+    this.stopped = true;
+    if (this.reconnectTimer) {
+      clearTimeout(this.reconnectTimer);
+      this.reconnectTimer = null;
+    }
+    this.socket?.destroy();
+    this.socket = null;
+    this.output?.end();
+    this.output = null;
+  }
+
+  private connect(): void {
+    const socket = tls.connect({ host: CHAT_HOST, port: CHAT_PORT, servername: CHAT_HOST });
+    this.socket = socket;
+    this.buffer = '';
+
+    socket.setEncoding('utf8');
+
+    socket.on('secureConnect', () => {
+      // justinfan<number> is Twitch's anonymous read-only login
+      socket.write(`NICK justinfan${Math.floor(10000 + Math.random() * 90000)}\r\n`);
+      socket.write(`JOIN #${this.channel}\r\n`);
+    });
+
+    socket.on('data', (data: string) => {
+      this.handleData(data);
+    });
+
+    socket.on('error', (error) => {
+      console.error(`Chat connection error for ${this.channel}:`, error.message);
+    });
+
+    socket.on('close', () => {
+      if (this.stopped || this.socket !== socket) return;
+      this.socket = null;
+      this.reconnectTimer = setTimeout(() => {
+        this.reconnectTimer = null;
+        if (!this.stopped) {
+          this.connect();
+        }
+      }, RECONNECT_DELAY_MS);
+    });
+  }
+
+  private handleData(data: string): void {
+    this.buffer += data;
+    const lines = this.buffer.split('\r\n');
+    // Keep any partial line until the rest of it arrives
+    this.buffer = lines.pop() ?? '';
+
+    for (const line of lines) {
+      if (line.startsWith('PING')) {
+        this.socket?.write(`PONG${line.slice(4)}\r\n`);
+        continue;
+      }
+
+      const message = parseChatMessage(line);
+      if (message) {
+        this.output?.write(`${formatChatLine(new Date(), message.username, message.message)}\n`);
+      }
+    }
+  }
+}
@@ -1,21 +1,73 @@
 import { spawn, ChildProcess } from 'child_process';
 import path from 'path';
 import fs from 'fs';
-import { RecordingModel, RecordingLogModel, StreamerModel, StatsModel } from '../models';
+import { RecordingModel, RecordingLogModel, StreamerModel, StatsModel, SettingsModel, type Streamer } from '../models';
 import { EventEmitter } from 'events';
-import { checkDiskSpaceForRecording, getDiskSpaceStatus, getMaxRecordingSizeMb, getMaxRecordingDurationMs } from '../utils/disk-space';
+import { checkDiskSpaceForRecording, checkDiskSpaceDuringRecording, getDiskSpaceStatus, getMaxRecordingSizeMb, getMaxRecordingDurationMs } from '../utils/disk-space';
+import { buildPostRecordingCommand, getPostRecordingCommand } from '../utils/post-recording-hook';
+import { sendWebhookNotification, WebhookEvent } from '../utils/webhook';
+import { getExtraStreamlinkArgs, parseSelectedStream, parseStreamNotFound } from '../utils/streamlink';
+import { writeMetadataSidecar } from '../utils/sidecar';
+import { runWithConcurrency } from '../utils/concurrency';
+import { toTitleSlug } from '../utils/filename';
+import { getScheduleWindowEnd, isWithinSchedule, parseSchedule } from '../utils/schedule';
+import { ChatRecorder } from './chat-recorder';
 
 const RECORDINGS_DIR = process.env.RECORDINGS_DIR || path.join(process.cwd(), 'recordings');
 
+// Live checks run in parallel, with a small random delay before each probe
+const LIVE_CHECK_CONCURRENCY = 4;
+const LIVE_CHECK_JITTER_MS = 500;
+
+// Why the app stopped a recording itself
+type StopReason = 'manual' | 'limit' | 'schedule' | 'rotation' | 'stall' | 'rejected';
+
+// Stream names streamlink lists that aren't renditions of their own
+const STREAM_ALIASES = ['best', 'worst'];
+
+const AUDIO_ONLY_REJECTED_MESSAGE = 'Only audio is available and audio-only recordings are disabled';
+
+// Stops that leave a complete, usable file behind
+const CLEAN_STOP_REASONS: ReadonlySet<StopReason> = new Set(['manual', 'limit', 'schedule', 'rotation']);
+
+interface RotationMetadata {
+  title: string;
+  category: string;
+  // Title-change rotations name the new file after the new title
+  nameAfterTitle?: boolean;
+}
+
 interface ActiveRecording {
   process: ChildProcess;
   recordingId: number;
   streamerId: number;
   username: string;
+  quality: string;
+  qualityResolved?: boolean;
   startTime: Date;
   filePath: string;
   fileSizeCheckInterval?: NodeJS.Timeout;
   durationCheckInterval?: NodeJS.Timeout;
+  metadataCheckInterval?: NodeJS.Timeout;
+  chatRecorder?: ChatRecorder;
+  streamTitle?: string;
+  streamCategory?: string;
+  lastFileSizeBytes: number;
+  lastGrowthAt: Date;
+  // File size at the previous size check, for the measured download speed
+  lastSizeSample?: { bytes: number; at: number };
+  bytesPerSecond?: number;
+  scheduleEndsAt?: Date;
+  // Wall-clock stop time requested when the recording was started
+  stopAt?: Date;
+  restartOnEnd?: boolean;
+  // Set for rotations: the stream is known to be live, so the restart skips the probes
+  rotationMetadata?: RotationMetadata;
+  stopReason?: StopReason;
+  // Streamlink's most recent stderr line, kept as the error message if it fails
+  lastStderrLine?: string;
+  // Error message for a recording the app stopped because it was unusable
+  errorMessage?: string;
 }
 
 export class RecordingService extends EventEmitter {
@@ -24,6 +76,8 @@ export class RecordingService extends EventEmitter {
   private isShuttingDown = false;
   private checkInProgress = false;
   private shutdownPromise: Promise<void> | null = null;
+  // Streamers whose current broadcast was rejected as audio-only; cleared once they go offline
+  private audioOnlyRejected: Set<number> = new Set();
 
   constructor() {
     super();
@@ -67,6 +121,7 @@ export class RecordingService extends EventEmitter {
       // First, update all active recordings in DB to completed status
       // This ensures DB is consistent even if process exits before handlers run
       const endTime = new Date();
+      const summaries = new Map<number, { durationSeconds: number; fileSizeBytes: number }>();
       for (const recording of activeRecordings) {
         const durationSeconds = Math.floor((endTime.getTime() - recording.startTime.getTime()) / 1000);
         
@@ -94,6 +149,7 @@ export class RecordingService extends EventEmitter {
           level: 'success',
         });
         
+        summaries.set(recording.recordingId, { durationSeconds, fileSizeBytes });
         console.log(`Updated recording ${recording.recordingId} for ${recording.username} to completed status`);
       }
       
@@ -108,6 +164,10 @@ export class RecordingService extends EventEmitter {
         if (recording.durationCheckInterval) {
           clearInterval(recording.durationCheckInterval);
         }
+        if (recording.metadataCheckInterval) {
+          clearInterval(recording.metadataCheckInterval);
+        }
+        recording.chatRecorder?.stop();
       }
       
       // Remove all from map BEFORE killing processes to prevent handleRecordingEnd from running
@@ -115,27 +175,49 @@ export class RecordingService extends EventEmitter {
       const recordingsToStop = [...activeRecordings];
       this.activeRecordings.clear();
       
-      // Now kill the processes
+      // Now kill the processes. Resolves true once the process has exited.
       const stopPromises = recordingsToStop.map(recording => {
-        return new Promise<void>((resolve) => {
+        return new Promise<boolean>((resolve) => {
+          let giveUpTimeout: NodeJS.Timeout | undefined;
+
           // Force kill after 2 seconds if not stopped
-          const timeout = setTimeout(() => {
-            if (!recording.process.killed) {
+          const forceKillTimeout = setTimeout(() => {
+            if (!this.hasExited(recording.process)) {
               recording.process.kill('SIGKILL');
             }
-            resolve();
+            // Don't hold up shutdown forever on a process that won't die
+            giveUpTimeout = setTimeout(() => resolve(this.hasExited(recording.process)), 1000);
           }, 2000);
           
           recording.process.on('close', () => {
-            clearTimeout(timeout);
-            resolve();
+            clearTimeout(forceKillTimeout);
+            clearTimeout(giveUpTimeout);
+            resolve(true);
           });
           
           recording.process.kill('SIGTERM');
         });
       });
       
-      await Promise.all(stopPromises);
+      const exited = await Promise.all(stopPromises);
+      
+      const notifications: Promise<void>[] = [];
+      recordingsToStop.forEach((recording, i) => {
+        const summary = summaries.get(recording.recordingId)!;
+
+        // The file may still be open for writing, so leave it where it is
+        if (exited[i]) {
+          this.applyQualityToFilename(recording);
+          this.saveMetadataSidecar(recording, { ...summary, endTime, status: 'completed', exitCode: null });
+          this.runPostRecordingHook(recording, summary.durationSeconds);
+        } else {
+          console.error(`Recording ${recording.recordingId} for ${recording.username} did not exit, skipping post-processing`);
+        }
+        notifications.push(this.notifyWebhook('recording.ended', recording, summary));
+      });
+      
+      // Give webhook notifications a chance to go out before the process exits
+      await Promise.all(notifications);
       
       console.log('All recordings stopped and database updated');
     }
@@ -187,65 +269,93 @@ export class RecordingService extends EventEmitter {
     this.checkInProgress = true;
     
     try {
-      const streamers = StreamerModel.findAll();
-      
-      for (const streamer of streamers) {
-        if (!streamer.auto_record) continue;
+      const candidates = StreamerModel.findAll().filter(streamer => {
+        if (!streamer.auto_record) return false;
+        if (!isWithinSchedule(streamer.record_schedule)) return false;
         
         // Double-check if already recording (in-memory check)
         if (this.isRecording(streamer.id)) {
           console.log(`Already recording ${streamer.username}, skipping...`);
-          continue;
+          return false;
         }
         
         // Also check database for any active recordings
         const existingRecording = RecordingModel.findActiveByStreamer(streamer.id);
         if (existingRecording) {
           console.log(`Found existing active recording for ${streamer.username} in DB, skipping...`);
-          continue;
+          return false;
         }
 
-        // Check if streamer is live
-        const isLive = await this.checkIfLive(streamer.username);
-        
-        if (isLive) {
-          // Final check before starting to prevent race conditions
-          if (this.isRecording(streamer.id)) {
-            console.log(`Recording for ${streamer.username} started by another check, skipping...`);
-            continue;
-          }
-          
-          // Check disk space before starting
-          const diskCheck = checkDiskSpaceForRecording(RECORDINGS_DIR);
-          if (!diskCheck.allowed) {
-            console.error(`Cannot start recording for ${streamer.username}: ${diskCheck.reason}`);
-            RecordingLogModel.create({
-              streamer_username: streamer.username,
-              message: `Recording blocked: ${diskCheck.reason}`,
-              level: 'error',
-            });
-            continue;
-          }
-          
-          try {
-            console.log(`Starting recording for ${streamer.username}...`);
-            await this.startRecording(streamer.id);
-            console.log(`Successfully started recording for ${streamer.username}`);
-          } catch (error) {
-            console.error(`Failed to start recording for ${streamer.username}:`, error);
-          }
-        }
-      }
+        return true;
+      });
+
+      // Probe several streamers at once, staggered so a large list doesn't hit Twitch in lockstep
+      await runWithConcurrency(
+        candidates,
+        LIVE_CHECK_CONCURRENCY,
+        streamer => this.checkAndRecordStreamer(streamer),
+        candidates.length > 1 ? LIVE_CHECK_JITTER_MS : 0
+      );
     } finally {
       this.checkInProgress = false;
     }
   }
 
+  // Start recording a single streamer if they are live
+  private async checkAndRecordStreamer(streamer: Streamer): Promise<void> {
+    const checkStartedAt = Date.now();
+    const isLive = await this.checkIfLive(streamer.username);
+    if (!isLive) {
+      this.audioOnlyRejected.delete(streamer.id);
+      return;
+    }
+
+    // Don't start, kill and restart an audio-only broadcast on every check
+    if (this.audioOnlyRejected.has(streamer.id) && !SettingsModel.get().allow_audio_only) return;
+    if (await this.isUnwantedAudioOnly(streamer)) {
+      this.audioOnlyRejected.add(streamer.id);
+      console.error(`Not recording ${streamer.username}: only audio is available`);
+      RecordingLogModel.create({
+        streamer_username: streamer.username,
+        message: `Recording skipped: ${AUDIO_ONLY_REJECTED_MESSAGE}. Retrying once the stream goes offline.`,
+        level: 'error',
+      });
+      return;
+    }
+
+    // Final check before starting to prevent race conditions
+    if (this.isRecording(streamer.id)) {
+      console.log(`Recording for ${streamer.username} started by another check, skipping...`);
+      return;
+    }
+    
+    // Check disk space before starting
+    const diskCheck = checkDiskSpaceForRecording(RECORDINGS_DIR);
+    if (!diskCheck.allowed) {
+      console.error(`Cannot start recording for ${streamer.username}: ${diskCheck.reason}`);
+      RecordingLogModel.create({
+        streamer_username: streamer.username,
+        message: `Recording blocked: ${diskCheck.reason}`,
+        level: 'error',
+      });
+      return;
+    }
+    
+    try {
+      console.log(`Starting recording for ${streamer.username}...`);
+      await this.startRecording(streamer.id);
+      console.log(`Successfully started recording for ${streamer.username} in ${Date.now() - checkStartedAt}ms`);
+    } catch (error) {
+      console.error(`Failed to start recording for ${streamer.username}:`, error);
+    }
+  }
+
   // Get stream metadata from streamlink
   async getStreamMetadata(username: string): Promise<{ title: string; category: string } | null> {
     return new Promise((resolve) => {
       const streamlink = spawn('streamlink', [
         '--json',
+        ...getExtraStreamlinkArgs(),
         `https://twitch.tv/${username}`,
         'best'
       ]);
@@ -289,6 +399,7 @@ export class RecordingService extends EventEmitter {
     return new Promise((resolve) => {
       const streamlink = spawn('streamlink', [
         '--json',
+        ...getExtraStreamlinkArgs(),
         `https://twitch.tv/${username}`,
         'best'
       ]);
@@ -321,8 +432,60 @@ export class RecordingService extends EventEmitter {
     });
   }
 
+  // List the stream names streamlink offers for a channel, or null if they couldn't be read
+  async getAvailableStreams(username: string): Promise<string[] | null> {
+    return new Promise((resolve) => {
+      const streamlink = spawn('streamlink', [
+        '--json',
+        ...getExtraStreamlinkArgs(),
+        `https://twitch.tv/${username}`,
+      ]);
+
+      let output = '';
+
+      streamlink.stdout.on('data', (data) => {
+        output += data.toString();
+      });
+
+      streamlink.on('close', () => {
+        try {
+          const result = JSON.parse(output);
+          resolve(result.streams ? Object.keys(result.streams) : null);
+        } catch {
+          resolve(null);
+        }
+      });
+
+      streamlink.on('error', () => {
+        resolve(null);
+      });
+
+      // Timeout after 10 seconds
+      setTimeout(() => {
+        streamlink.kill();
+        resolve(null);
+      }, 10000);
+    });
+  }
+
+  // Whether a live channel only offers audio that the streamer's quality didn't ask for
+  private async isUnwantedAudioOnly(streamer: Streamer): Promise<boolean> {
+    if (SettingsModel.get().allow_audio_only) return false;
+    const quality = streamer.quality_preference || 'best';
+    if (quality.split(',').includes('audio_only')) return false;
+
+    const streams = await this.getAvailableStreams(streamer.username);
+    if (!streams) return false;
+    const renditions = streams.filter(name => !STREAM_ALIASES.includes(name));
+    return renditions.length > 0 && renditions.every(name => name === 'audio_only');
+  }
+
   // Start recording a streamer
-  async startRecording(streamerId: number): Promise<number> {
+  async startRecording(
+    streamerId: number,
+    options: { knownMetadata?: RotationMetadata; stopAt?: Date } = {}
+  ): Promise<number> {
+    const { knownMetadata, stopAt } = options;
     // Don't start new recordings during shutdown
     if (this.isShuttingDown) {
       throw new Error('Cannot start recording: service is shutting down');
@@ -349,15 +512,39 @@ export class RecordingService extends EventEmitter {
       throw new Error(`Cannot start recording: ${diskCheck.reason}`);
     }
 
+    const outputDir = SettingsModel.get().per_streamer_dirs
+      ? path.join(RECORDINGS_DIR, streamer.username)
+      : RECORDINGS_DIR;
+    try {
+      this.ensureOutputDir(outputDir);
+    } catch (error) {
+      const reason = error instanceof Error ? error.message : String(error);
+      RecordingLogModel.create({
+        streamer_username: streamer.username,
+        message: `Recording blocked: ${reason}`,
+        level: 'error',
+      });
+      throw new Error(`Cannot start recording: ${reason}`);
+    }
+
     // Get stream metadata before starting
-    const metadata = await this.getStreamMetadata(streamer.username);
+    const metadata = knownMetadata ?? await this.getStreamMetadata(streamer.username);
 
     // Create filename with timestamp
     const timestamp = new Date().toISOString().replace(/[:.]/g, '-');
-    const filename = `${streamer.username}_${timestamp}.mp4`;
-    const filePath = path.join(RECORDINGS_DIR, filename);
+    const titleSlug = knownMetadata?.nameAfterTitle ? toTitleSlug(knownMetadata.title) : '';
+    const baseName = titleSlug
+      ? `${streamer.username}_${timestamp}_${titleSlug}`
+      : `${streamer.username}_${timestamp}`;
+
+    let filename = `${baseName}.mp4`;
+    // Never overwrite an earlier recording (same-second restarts, a bad system clock)
+    for (let i = 1; fs.existsSync(path.join(outputDir, filename)); i++) {
+      filename = `${baseName}_${i}.mp4`;
+    }
+    const filePath = path.join(outputDir, filename);
     // Store relative path for web access
-    const relativeFilePath = `recordings/${filename}`;
+    const relativeFilePath = this.toRelativeFilePath(filePath);
 
     // Create recording record with metadata (use relative path for web access)
     const recording = RecordingModel.create({
@@ -373,20 +560,34 @@ export class RecordingService extends EventEmitter {
     const streamlink = spawn('streamlink', [
       '--twitch-disable-ads',
       '--twitch-low-latency',
+      ...getExtraStreamlinkArgs(),
       '-o', filePath,
       `https://twitch.tv/${streamer.username}`,
       quality
     ]);
 
+    const startTime = new Date();
     const activeRecording: ActiveRecording = {
       process: streamlink,
       recordingId: recording.id,
       streamerId,
       username: streamer.username,
-      startTime: new Date(),
+      quality,
+      startTime,
       filePath,
+      lastFileSizeBytes: 0,
+      lastGrowthAt: startTime,
+      streamTitle: metadata?.title,
+      streamCategory: metadata?.category,
     };
 
+    // Recordings started inside the streamer's schedule window stop when it ends
+    const schedule = streamer.record_schedule ? parseSchedule(streamer.record_schedule) : null;
+    if (schedule) {
+      activeRecording.scheduleEndsAt = getScheduleWindowEnd(schedule, startTime) ?? undefined;
+    }
+    activeRecording.stopAt = stopAt;
+
     this.activeRecordings.set(streamerId, activeRecording);
     
     // Update stats in database to reflect the actual in-memory active recordings count
@@ -405,6 +606,26 @@ export class RecordingService extends EventEmitter {
       }, 60000); // Check every minute
     }
 
+    // Set up title/category monitoring if rotation on title change is enabled
+    if (SettingsModel.get().rotate_on_title_change) {
+      activeRecording.metadataCheckInterval = setInterval(() => {
+        this.checkTitleChange(streamerId);
+      }, 60000); // Check every minute
+    }
+
+    // Record chat alongside the video if enabled
+    if (SettingsModel.get().record_chat) {
+      const chatFilePath = this.toChatFilePath(filePath);
+      activeRecording.chatRecorder = new ChatRecorder(streamer.username, chatFilePath);
+      activeRecording.chatRecorder.start();
+      RecordingLogModel.create({
+        recording_id: recording.id,
+        streamer_username: streamer.username,
+        message: `Recording chat to ${path.basename(chatFilePath)}`,
+        level: 'info',
+      });
+    }
+
     // Handle process events
     streamlink.on('close', (code) => {
       this.handleRecordingEnd(streamerId, code);
@@ -424,6 +645,8 @@ export class RecordingService extends EventEmitter {
     streamlink.stdout?.on('data', (data) => {
       const message = data.toString().trim();
       if (message) {
+        this.updateSelectedQuality(streamerId, message);
+        this.explainMissingQuality(streamerId, message);
         RecordingLogModel.create({
           recording_id: recording.id,
           streamer_username: streamer.username,
@@ -437,6 +660,9 @@ export class RecordingService extends EventEmitter {
     streamlink.stderr?.on('data', (data) => {
       const message = data.toString().trim();
       if (message) {
+        activeRecording.lastStderrLine = message.split('\n').pop()!.trim();
+        this.updateSelectedQuality(streamerId, message);
+        this.explainMissingQuality(streamerId, message);
         RecordingLogModel.create({
           recording_id: recording.id,
           streamer_username: streamer.username,
@@ -464,6 +690,7 @@ export class RecordingService extends EventEmitter {
     });
 
     this.emit('recordingStarted', { recordingId: recording.id, streamerId, username: streamer.username });
+    this.notifyWebhook('recording.started', activeRecording, { durationSeconds: 0, fileSizeBytes: 0 });
 
     return recording.id;
   }
@@ -473,6 +700,8 @@ export class RecordingService extends EventEmitter {
     const recording = this.activeRecordings.get(streamerId);
     if (!recording) return;
 
+    if (this.checkScheduleEnd(streamerId)) return;
+
     try {
       const stats = fs.statSync(recording.filePath);
       const fileSizeBytes = stats.size;
@@ -482,6 +711,10 @@ export class RecordingService extends EventEmitter {
       RecordingModel.update(recording.recordingId, {
         file_size_bytes: fileSizeBytes,
       });
+      this.updateDownloadSpeed(recording, fileSizeBytes);
+
+      if (this.checkRecordingStall(streamerId, fileSizeBytes)) return;
+      if (this.checkDailyRotation(streamerId)) return;
 
       // Check if size limit is exceeded
       const maxRecordingSizeMb = getMaxRecordingSizeMb();
@@ -493,13 +726,275 @@ export class RecordingService extends EventEmitter {
           message: `Recording stopped: exceeded maximum file size limit (${maxRecordingSizeMb}MB)`,
           level: 'warn',
         });
-        this.stopRecording(streamerId);
+        this.stopRecording(streamerId, 'limit');
+        return;
+      }
+
+      // Stop cleanly before the disk fills up so the file isn't left with a partial write
+      const diskCheck = checkDiskSpaceDuringRecording(RECORDINGS_DIR);
+      if (!diskCheck.allowed) {
+        console.error(`Recording ${recording.recordingId} stopping: ${diskCheck.reason}`);
+        RecordingLogModel.create({
+          recording_id: recording.recordingId,
+          streamer_username: recording.username,
+          message: `Recording stopped: ${diskCheck.reason}`,
+          level: 'error',
+        });
+        this.stopRecording(streamerId, 'limit');
       }
     } catch (error) {
       // File might not exist yet
+      this.checkRecordingStall(streamerId, 0);
+    }
+  }
+
+  // Measure how fast the file grew since the previous size check
+  private updateDownloadSpeed(recording: ActiveRecording, fileSizeBytes: number): void {
+    const now = Date.now();
+    const previous = recording.lastSizeSample;
+    if (previous && now > previous.at) {
+      recording.bytesPerSecond = Math.max(0, fileSizeBytes - previous.bytes) / ((now - previous.at) / 1000);
+    }
+    recording.lastSizeSample = { bytes: fileSizeBytes, at: now };
+  }
+
+  // Restart a recording whose file has stopped growing. Returns true if a restart was triggered.
+  private checkRecordingStall(streamerId: number, fileSizeBytes: number): boolean {
+    const recording = this.activeRecordings.get(streamerId);
+    if (!recording || recording.restartOnEnd) return false;
+
+    if (fileSizeBytes > recording.lastFileSizeBytes) {
+      recording.lastFileSizeBytes = fileSizeBytes;
+      recording.lastGrowthAt = new Date();
+      return false;
+    }
+
+    const stallTimeoutSeconds = SettingsModel.get().stall_timeout_seconds;
+    if (!stallTimeoutSeconds || stallTimeoutSeconds <= 0) return false;
+
+    const stalledSeconds = Math.floor((Date.now() - recording.lastGrowthAt.getTime()) / 1000);
+    if (stalledSeconds < stallTimeoutSeconds) return false;
+
+    console.log(`Recording ${recording.recordingId} stalled (no new data for ${stalledSeconds}s), restarting...`);
+    RecordingLogModel.create({
+      recording_id: recording.recordingId,
+      streamer_username: recording.username,
+      message: `Recording stalled: no new data for ${stalledSeconds}s, restarting`,
+      level: 'warn',
+    });
+    recording.restartOnEnd = true;
+    this.stopRecording(streamerId, 'stall');
+    return true;
+  }
+
+  // Record the stream variant streamlink actually opened (e.g. "best" resolves to "1080p60")
+  private updateSelectedQuality(streamerId: number, output: string): void {
+    const recording = this.activeRecordings.get(streamerId);
+    if (!recording) return;
+
+    const selected = parseSelectedStream(output);
+    if (!selected) return;
+
+    // "best" resolves to audio_only when a channel has no video renditions
+    const unexpectedAudioOnly = selected === 'audio_only' && !recording.quality.split(',').includes('audio_only');
+    const allowAudioOnly = !!SettingsModel.get().allow_audio_only;
+    if (unexpectedAudioOnly) {
+      RecordingLogModel.create({
+        recording_id: recording.recordingId,
+        streamer_username: recording.username,
+        message: allowAudioOnly
+          ? `Only audio is available for ${recording.username}, recording audio only`
+          : `Only audio is available for ${recording.username}; stopping because audio-only recordings are disabled`,
+        level: allowAudioOnly ? 'warn' : 'error',
+      });
+    }
+
+    recording.quality = selected;
+    recording.qualityResolved = true;
+    RecordingModel.update(recording.recordingId, { quality: selected });
+    RecordingLogModel.create({
+      recording_id: recording.recordingId,
+      streamer_username: recording.username,
+      message: `Recording quality: ${selected}`,
+      level: 'info',
+    });
+
+    if (unexpectedAudioOnly && !allowAudioOnly) {
+      this.audioOnlyRejected.add(streamerId);
+      recording.errorMessage = AUDIO_ONLY_REJECTED_MESSAGE;
+      recording.restartOnEnd = false;
+      this.stopRecording(streamerId, 'rejected');
+    }
+  }
+
+  // Turn streamlink's "stream(s) could not be found" error into an actionable log entry
+  private explainMissingQuality(streamerId: number, output: string): void {
+    const recording = this.activeRecordings.get(streamerId);
+    if (!recording) return;
+
+    const notFound = parseStreamNotFound(output);
+    if (!notFound) return;
+
+    const videoStreams = notFound.available.filter(name => name !== 'audio_only');
+    let message = `Quality "${notFound.requested}" is not available for ${recording.username}.`;
+    if (videoStreams.length === 1) {
+      // Channels without transcoding (typically non-partners) only offer the source rendition
+      message += ` The channel only offers its source quality (${videoStreams[0]}).`;
+    } else if (notFound.available.length > 0) {
+      message += ` Available: ${notFound.available.join(', ')}.`;
+    }
+    message += ` Set the quality preference to "best" or add a fallback such as "${notFound.requested},best".`;
+
+    RecordingLogModel.create({
+      recording_id: recording.recordingId,
+      streamer_username: recording.username,
+      message,
+      level: 'error',
+    });
+  }
+
+  // Create the output directory if needed, failing clearly if the path is taken by a file
+  private ensureOutputDir(dir: string): void {
+    let stats: fs.Stats | undefined;
+    try {
+      stats = fs.statSync(dir);
+    } catch {
+      // Doesn't exist yet
+    }
+
+    if (stats && !stats.isDirectory()) {
+      throw new Error(`Recordings path ${dir} exists but is not a directory`);
+    }
+
+    fs.mkdirSync(dir, { recursive: true });
+  }
+
+  // Path stored in the database and used for web access, e.g. recordings/<streamer>/<file>.mp4
+  private toRelativeFilePath(filePath: string): string {
+    return ['recordings', ...path.relative(RECORDINGS_DIR, filePath).split(path.sep)].join('/');
+  }
+
+  // Chat transcript path for a recording, e.g. <name>.mp4 -> <name>.chat.txt
+  private toChatFilePath(filePath: string): string {
+    return filePath.replace(/\.mp4$/, '.chat.txt');
+  }
+
+  // Rename a finished recording to include the quality streamlink selected, if enabled
+  private applyQualityToFilename(recording: ActiveRecording): void {
+    if (!recording.qualityResolved || !SettingsModel.get().quality_in_filename) return;
+
+    const parsed = path.parse(recording.filePath);
+    const quality = recording.quality.replace(/[^a-zA-Z0-9_-]/g, '_');
+    const baseName = `${parsed.name}_${quality}`;
+    let filename = `${baseName}${parsed.ext}`;
+    // renameSync replaces an existing target, so never reuse an earlier recording's name
+    for (let i = 1; fs.existsSync(path.join(parsed.dir, filename)); i++) {
+      filename = `${baseName}_${i}${parsed.ext}`;
+    }
+    const filePath = path.join(parsed.dir, filename);
+
+    try {
+      fs.renameSync(recording.filePath, filePath);
+
+      // Keep the chat transcript next to the video under the same name
+      const chatFilePath = this.toChatFilePath(recording.filePath);
+      if (recording.chatRecorder && fs.existsSync(chatFilePath)) {
+        fs.renameSync(chatFilePath, this.toChatFilePath(filePath));
+      }
+
+      recording.filePath = filePath;
+      RecordingModel.update(recording.recordingId, { file_path: this.toRelativeFilePath(filePath) });
+    } catch (error) {
+      console.error(`Failed to rename recording ${recording.recordingId}:`, error);
+      RecordingLogModel.create({
+        recording_id: recording.recordingId,
+        streamer_username: recording.username,
+        message: `Failed to add quality to filename: ${error instanceof Error ? error.message : String(error)}`,
+        level: 'warn',
+      });
     }
   }
 
+  // Roll over to a new file when the local date changes. Returns true if a rotation was triggered.
+  private checkDailyRotation(streamerId: number): boolean {
+    const recording = this.activeRecordings.get(streamerId);
+    if (!recording || recording.restartOnEnd) return false;
+
+    if (recording.startTime.toDateString() === new Date().toDateString()) return false;
+    if (!SettingsModel.get().rotate_daily) return false;
+
+    console.log(`Recording ${recording.recordingId} rotating at date change...`);
+    RecordingLogModel.create({
+      recording_id: recording.recordingId,
+      streamer_username: recording.username,
+      message: 'Date changed, rotating to a new recording file',
+      level: 'info',
+    });
+    recording.rotationMetadata = { title: recording.streamTitle ?? '', category: recording.streamCategory ?? '' };
+    recording.restartOnEnd = true;
+    this.stopRecording(streamerId, 'rotation');
+    return true;
+  }
+
+  // Stop a recording when its schedule window ends or its requested stop time is reached.
+  // Returns true if it was stopped.
+  private checkScheduleEnd(streamerId: number): boolean {
+    const recording = this.activeRecordings.get(streamerId);
+    if (!recording) return false;
+
+    const now = new Date();
+    let reason: string;
+    if (recording.stopAt && now >= recording.stopAt) {
+      reason = 'reached the requested stop time';
+    } else if (recording.scheduleEndsAt && now >= recording.scheduleEndsAt) {
+      reason = 'schedule window ended';
+    } else {
+      return false;
+    }
+
+    console.log(`Recording ${recording.recordingId} stopping: ${reason}`);
+    RecordingLogModel.create({
+      recording_id: recording.recordingId,
+      streamer_username: recording.username,
+      message: `Recording stopped: ${reason}`,
+      level: 'info',
+    });
+    recording.restartOnEnd = false;
+    this.stopRecording(streamerId, 'schedule');
+    return true;
+  }
+
+  // Roll over to a new file when the stream title or category changes
+  private async checkTitleChange(streamerId: number): Promise<void> {
+    const recording = this.activeRecordings.get(streamerId);
+    if (!recording || recording.restartOnEnd) return;
+
+    const metadata = await this.getStreamMetadata(recording.username);
+    if (!metadata?.title) return;
+
+    // The recording may have ended while metadata was being fetched
+    if (this.activeRecordings.get(streamerId) !== recording || recording.restartOnEnd) return;
+
+    if (!recording.streamTitle) {
+      recording.streamTitle = metadata.title;
+      recording.streamCategory = metadata.category;
+      return;
+    }
+
+    if (metadata.title === recording.streamTitle && metadata.category === recording.streamCategory) return;
+
+    console.log(`Recording ${recording.recordingId} rotating on title change...`);
+    RecordingLogModel.create({
+      recording_id: recording.recordingId,
+      streamer_username: recording.username,
+      message: `Stream title changed to "${metadata.title}", rotating to a new recording file`,
+      level: 'info',
+    });
+    recording.rotationMetadata = { ...metadata, nameAfterTitle: true };
+    recording.restartOnEnd = true;
+    this.stopRecording(streamerId, 'rotation');
+  }
+
   // Check if recording duration exceeds limit
   private checkRecordingDuration(streamerId: number): void {
     const maxRecordingDurationMs = getMaxRecordingDurationMs();
@@ -516,12 +1011,12 @@ export class RecordingService extends EventEmitter {
         message: `Recording stopped: exceeded maximum duration limit (${hours} hours)`,
         level: 'warn',
       });
-      this.stopRecording(streamerId);
+      this.stopRecording(streamerId, 'limit');
     }
   }
 
   // Stop a recording
-  async stopRecording(streamerId: number): Promise<boolean> {
+  async stopRecording(streamerId: number, reason: StopReason = 'manual'): Promise<boolean> {
     const recording = this.activeRecordings.get(streamerId);
     if (!recording) {
       return false;
@@ -534,12 +1029,17 @@ export class RecordingService extends EventEmitter {
     if (recording.durationCheckInterval) {
       clearInterval(recording.durationCheckInterval);
     }
+    if (recording.metadataCheckInterval) {
+      clearInterval(recording.metadataCheckInterval);
+    }
+    recording.chatRecorder?.stop();
 
+    recording.stopReason ??= reason;
     recording.process.kill('SIGTERM');
     
     // Force kill after 5 seconds if still running
     setTimeout(() => {
-      if (!recording.process.killed) {
+      if (!this.hasExited(recording.process)) {
         recording.process.kill('SIGKILL');
       }
     }, 5000);
@@ -547,6 +1047,11 @@ export class RecordingService extends EventEmitter {
     return true;
   }
 
+  // `killed` only means a signal was sent; the exit and signal codes are set once the process is gone
+  private hasExited(process: ChildProcess): boolean {
+    return process.exitCode !== null || process.signalCode !== null;
+  }
+
   // Handle recording end
   private async handleRecordingEnd(streamerId: number, exitCode: number | null): Promise<void> {
     // Don't process recording end if we're shutting down (shutdown already handled everything)
@@ -564,6 +1069,10 @@ export class RecordingService extends EventEmitter {
     if (recording.durationCheckInterval) {
       clearInterval(recording.durationCheckInterval);
     }
+    if (recording.metadataCheckInterval) {
+      clearInterval(recording.metadataCheckInterval);
+    }
+    recording.chatRecorder?.stop();
 
     this.activeRecordings.delete(streamerId);
     
@@ -582,15 +1091,27 @@ export class RecordingService extends EventEmitter {
       // File might not exist if recording failed
     }
 
-    // Update recording status
-    const status = exitCode === 0 ? 'completed' : 'stopped';
+    // Update recording status. Streamlink exiting non-zero on its own is a failure;
+    // a non-zero exit after we stopped it is not, unless we stopped it as unusable.
+    const rejected = recording.stopReason === 'rejected';
+    const failed = rejected || (!recording.stopReason && exitCode !== 0);
+    const status = failed ? 'error' : exitCode === 0 ? 'completed' : 'stopped';
     RecordingModel.update(recording.recordingId, {
       status,
       ended_at: endTime.toISOString(),
       duration_seconds: durationSeconds,
       file_size_bytes: fileSizeBytes,
+      ...(failed && {
+        error_message: recording.errorMessage || recording.lastStderrLine || `streamlink exited with code ${exitCode}`,
+      }),
     });
 
+    // A rejected recording isn't a finished recording; leave its file as is
+    if (!rejected) {
+      this.applyQualityToFilename(recording);
+      this.saveMetadataSidecar(recording, { endTime, durationSeconds, fileSizeBytes, status, exitCode });
+    }
+
     // Log end
     RecordingLogModel.create({
       recording_id: recording.recordingId,
@@ -607,6 +1128,172 @@ export class RecordingService extends EventEmitter {
       durationSeconds,
       fileSizeBytes
     });
+    this.notifyWebhook('recording.ended', recording, { durationSeconds, fileSizeBytes, exitCode });
+
+    // Only hand successful recordings to the post-recording hook. Streamlink's exit code after
+    // our own SIGTERM says nothing about the file, so app-initiated stops go by their reason.
+    const succeeded = recording.stopReason ? CLEAN_STOP_REASONS.has(recording.stopReason) : exitCode === 0;
+    if (succeeded) {
+      this.runPostRecordingHook(recording, durationSeconds);
+    }
+
+    if (recording.restartOnEnd) {
+      await this.restartRecording(streamerId, recording);
+    }
+  }
+
+  // Write a <recording>.json provenance file next to the video, if enabled
+  private saveMetadataSidecar(
+    recording: ActiveRecording,
+    summary: { endTime: Date; durationSeconds: number; fileSizeBytes: number; status: string; exitCode: number | null }
+  ): void {
+    if (!SettingsModel.get().metadata_sidecar) return;
+
+    try {
+      writeMetadataSidecar(recording.filePath, {
+        recordingId: recording.recordingId,
+        streamer: recording.username,
+        title: recording.streamTitle ?? null,
+        category: recording.streamCategory ?? null,
+        quality: recording.quality,
+        url: `https://twitch.tv/${recording.username}`,
+        file: this.toRelativeFilePath(recording.filePath),
+        startedAt: recording.startTime.toISOString(),
+        endedAt: summary.endTime.toISOString(),
+        durationSeconds: summary.durationSeconds,
+        fileSizeBytes: summary.fileSizeBytes,
+        status: summary.status,
+        exitCode: summary.exitCode,
+      });
+    } catch (error) {
+      console.error(`Failed to write metadata sidecar for recording ${recording.recordingId}:`, error);
+      RecordingLogModel.create({
+        recording_id: recording.recordingId,
+        streamer_username: recording.username,
+        message: `Failed to write metadata sidecar: ${error instanceof Error ? error.message : String(error)}`,
+        level: 'warn',
+      });
+    }
+  }
+
+  // Run the POST_RECORDING_COMMAND hook for a finished recording
+  private runPostRecordingHook(recording: ActiveRecording, durationSeconds: number): void {
+    const template = getPostRecordingCommand();
+    if (!template) return;
+
+    const command = buildPostRecordingCommand(template, {
+      file: recording.filePath,
+      streamer: recording.username,
+      duration: durationSeconds,
+    });
+
+    try {
+      const hook = spawn(command, [], { shell: true, stdio: 'ignore' });
+
+      hook.on('close', (code) => {
+        RecordingLogModel.create({
+          recording_id: recording.recordingId,
+          streamer_username: recording.username,
+          message: `Post-recording command exited with code ${code}`,
+          level: code === 0 ? 'info' : 'warn',
+        });
+      });
+
+      hook.on('error', (error) => {
+        console.error(`Post-recording command failed for ${recording.username}:`, error);
+        RecordingLogModel.create({
+          recording_id: recording.recordingId,
+          streamer_username: recording.username,
+          message: `Post-recording command failed: ${error.message}`,
+          level: 'error',
+        });
+      });
+    } catch (error) {
+      console.error(`Failed to run post-recording command for ${recording.username}:`, error);
+    }
+  }
+
+  // Send a recording event to the configured webhook, if any. Failures are logged, never thrown.
+  private async notifyWebhook(
+    event: WebhookEvent,
+    recording: ActiveRecording,
+    summary: { durationSeconds: number; fileSizeBytes: number; exitCode?: number | null }
+  ): Promise<void> {
+    const url = SettingsModel.get().notify_webhook_url;
+    if (!url) return;
+
+    const sent = await sendWebhookNotification(url, {
+      event,
+      recordingId: recording.recordingId,
+      streamer: recording.username,
+      quality: recording.quality,
+      // Relative to the recordings dir, so third-party services don't learn the server layout
+      file: this.toRelativeFilePath(recording.filePath),
+      fileSizeBytes: summary.fileSizeBytes,
+      durationSeconds: summary.durationSeconds,
+      exitCode: summary.exitCode,
+      timestamp: new Date().toISOString(),
+    });
+
+    if (!sent) {
+      RecordingLogModel.create({
+        streamer_username: recording.username,
+        message: `Failed to send ${event} webhook notification`,
+        level: 'warn',
+      });
+    }
+  }
+
+  // Start a fresh recording after a stalled or rotated one ended, if the streamer is still live
+  private async restartRecording(streamerId: number, previous: ActiveRecording): Promise<void> {
+    const { username, rotationMetadata, scheduleEndsAt, stopAt } = previous;
+
+    // A restart never extends a recording past the schedule window it started in or its stop time
+    if (stopAt && new Date() >= stopAt) {
+      RecordingLogModel.create({
+        streamer_username: username,
+        message: 'Requested stop time reached, not restarting recording',
+        level: 'info',
+      });
+      return;
+    }
+    if (scheduleEndsAt && new Date() >= scheduleEndsAt) {
+      RecordingLogModel.create({
+        streamer_username: username,
+        message: 'Schedule window ended, not restarting recording',
+        level: 'info',
+      });
+      return;
+    }
+
+    // Rotations restart straight away so only the process restart is lost from the stream
+    const isLive = rotationMetadata ? true : await this.checkIfLive(username);
+    if (!isLive) {
+      RecordingLogModel.create({
+        streamer_username: username,
+        message: 'Stream is no longer live, not restarting recording',
+        level: 'info',
+      });
+      return;
+    }
+
+    if (this.isShuttingDown || this.isRecording(streamerId)) return;
+
+    try {
+      await this.startRecording(streamerId, { knownMetadata: rotationMetadata, stopAt });
+      // The window may have ended while restarting; keep the old end so the next check stops it
+      const restarted = this.activeRecordings.get(streamerId);
+      if (restarted && scheduleEndsAt && !restarted.scheduleEndsAt) {
+        restarted.scheduleEndsAt = scheduleEndsAt;
+      }
+    } catch (error) {
+      console.error(`Failed to restart recording for ${username}:`, error);
+      RecordingLogModel.create({
+        streamer_username: username,
+        message: `Failed to restart recording: ${error instanceof Error ? error.message : String(error)}`,
+        level: 'error',
+      });
+    }
   }
 
   // Check if a streamer is being recorded
@@ -619,6 +1306,7 @@ export class RecordingService extends EventEmitter {
     recordingId: number;
     streamerId: number;
     username: string;
+    quality: string;
     startTime: Date;
     filePath: string;
   }> {
@@ -626,25 +1314,42 @@ export class RecordingService extends EventEmitter {
       recordingId: r.recordingId,
       streamerId: r.streamerId,
       username: r.username,
+      quality: r.quality,
       startTime: r.startTime,
       filePath: r.filePath,
     }));
   }
 
+  // Get how long each active recording's file has gone without new data
+  getRecordingHealth(): Array<{
+    recordingId: number;
+    streamerId: number;
+    username: string;
+    secondsSinceLastData: number;
+  }> {
+    const now = Date.now();
+    return Array.from(this.activeRecordings.values()).map(r => ({
+      recordingId: r.recordingId,
+      streamerId: r.streamerId,
+      username: r.username,
+      secondsSinceLastData: Math.floor((now - r.lastGrowthAt.getTime()) / 1000),
+    }));
+  }
+
   // Get active recordings count
   getActiveCount(): number {
     return this.activeRecordings.size;
   }
 
-  // Get total download speed (approximate)
+  // Get total download speed, measured from file growth between size checks
   getTotalDownloadSpeed(): string {
-    // This is a simplified calculation - in a real app you'd track bytes over time
-    const activeCount = this.activeRecordings.size;
-    if (activeCount === 0) return '0 MB/s';
-    
-    // Estimate ~6 MB/s per 1080p60 stream
-    const estimatedSpeed = activeCount * 6;
-    return `~${estimatedSpeed} MB/s`;
+    if (this.activeRecordings.size === 0) return '0 MB/s';
+
+    let bytesPerSecond = 0;
+    for (const recording of this.activeRecordings.values()) {
+      bytesPerSecond += recording.bytesPerSecond ?? 0;
+    }
+    return `${(bytesPerSecond / (1024 * 1024)).toFixed(1)} MB/s`;
   }
 
   // Get disk space status
@@ -669,6 +1374,7 @@ export class RecordingService extends EventEmitter {
   // Reset method for tests - clears all internal state
   reset(): void {
     this.activeRecordings.clear();
+    this.audioOnlyRejected.clear();
     this.checkInProgress = false;
     this.isShuttingDown = false;
     this.shutdownPromise = null;
@@ -0,0 +1,72 @@
+import { describe, it, expect, vi, afterEach } from 'vitest';
+import { runWithConcurrency } from '../concurrency';
+
+const delay = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe('runWithConcurrency', () => {
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.restoreAllMocks();
+  });
+
+  it('should process every item', async () => {
+    const seen: number[] = [];
+
+    await runWithConcurrency([1, 2, 3, 4, 5], 2, async (item) => {
+      seen.push(item);
+    });
+
+    expect(seen.sort()).toEqual([1, 2, 3, 4, 5]);
+  });
+
+  it('should never exceed the concurrency limit', async () => {
+    let inFlight = 0;
+    let maxInFlight = 0;
+
+    await runWithConcurrency([1, 2, 3, 4, 5, 6], 3, async () => {
+      inFlight++;
+      maxInFlight = Math.max(maxInFlight, inFlight);
+      await delay(5);
+      inFlight--;
+    });
+
+    expect(maxInFlight).toBe(3);
+  });
+
+  it('should treat a limit below 1 as sequential', async () => {
+    let maxInFlight = 0;
+    let inFlight = 0;
+
+    await runWithConcurrency([1, 2, 3], 0, async () => {
+      inFlight++;
+      maxInFlight = Math.max(maxInFlight, inFlight);
+      await delay(1);
+      inFlight--;
+    });
+
+    expect(maxInFlight).toBe(1);
+  });
+
+  it('should handle an empty list', async () => {
+    const worker = vi.fn(async () => {});
+
+    await runWithConcurrency([], 4, worker);
+
+    expect(worker).not.toHaveBeenCalled();
+  });
+
+  it('should delay each item by up to the jitter', async () => {
+    vi.useFakeTimers();
+    vi.spyOn(Math, 'random').mockReturnValue(0.5);
+    const worker = vi.fn(async () => {});
+
+    const promise = runWithConcurrency(['a'], 1, worker, 1000);
+
+    await vi.advanceTimersByTimeAsync(499);
+    expect(worker).not.toHaveBeenCalled();
+
+    await vi.advanceTimersByTimeAsync(1);
+    await promise;
+    expect(worker).toHaveBeenCalledWith('a');
+  });
+});
@@ -0,0 +1,50 @@
+import { describe, it, expect } from 'vitest';
+import { escapeCsvField, toCsv } from '../csv';
+
+describe('csv utilities', () => {
+  describe('escapeCsvField', () => {
+    it('should leave plain values unquoted', () => {
+      expect(escapeCsvField('testuser')).toBe('testuser');
+      expect(escapeCsvField(3600)).toBe('3600');
+    });
+
+    it('should render null and undefined as empty fields', () => {
+      expect(escapeCsvField(null)).toBe('');
+      expect(escapeCsvField(undefined)).toBe('');
+    });
+
+    it('should quote values with commas, quotes or newlines', () => {
+      expect(escapeCsvField('Ranked, day 2')).toBe('"Ranked, day 2"');
+      expect(escapeCsvField('the "final" match')).toBe('"the ""final"" match"');
+      expect(escapeCsvField('line one\nline two')).toBe('"line one\nline two"');
+    });
+
+    it('should neutralize text that spreadsheets would run as a formula', () => {
+      expect(escapeCsvField('=HYPERLINK("http://evil.example","click")')).toBe('"\'=HYPERLINK(""http://evil.example"",""click"")"');
+      expect(escapeCsvField('+1 sub')).toBe("'+1 sub");
+      expect(escapeCsvField('-5 viewers')).toBe("'-5 viewers");
+      expect(escapeCsvField('@everyone')).toBe("'@everyone");
+      expect(escapeCsvField('\tindented')).toBe("'\tindented");
+      expect(escapeCsvField('\rcmd')).toBe('"\'\rcmd"');
+    });
+
+    it('should leave numbers alone', () => {
+      expect(escapeCsvField(-5)).toBe('-5');
+    });
+  });
+
+  describe('toCsv', () => {
+    it('should write a header row and one row per item', () => {
+      const csv = toCsv(['id', 'title'], [
+        { id: 1, title: 'First' },
+        { id: 2, title: 'Second, again' },
+      ]);
+
+      expect(csv).toBe('id,title\r\n1,First\r\n2,"Second, again"\r\n');
+    });
+
+    it('should only write the header for no rows', () => {
+      expect(toCsv<{ id: number }>(['id'], [])).toBe('id\r\n');
+    });
+  });
+});
@@ -2,11 +2,13 @@ import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import {
   getDiskSpaceInfo,
   checkDiskSpaceForRecording,
+  checkDiskSpaceDuringRecording,
   getTotalRecordingsSizeMb,
   formatBytes,
   getDiskSpaceStatus,
   getMaxRecordingSizeMb,
   getMaxRecordingDurationMs,
+  clearRecordingsSizeCache,
 } from '../disk-space';
 import fs from 'fs';
 
@@ -43,6 +45,7 @@ describe('disk-space utilities', () => {
 
   beforeEach(() => {
     vi.clearAllMocks();
+    clearRecordingsSizeCache();
     // Reset default mock values
     mockGetDiskLimits.mockReturnValue({
       minFreeMb: 5000,
@@ -176,6 +179,120 @@ describe('disk-space utilities', () => {
     });
   });
 
+  describe('checkDiskSpaceDuringRecording', () => {
+    it('should allow recording to continue above the minimum free space', () => {
+      mockStatSync.mockReturnValue({ isDirectory: () => true });
+      mockStatfsSync.mockReturnValue({
+        bsize: 4096,
+        blocks: 10000000,
+        bavail: 2000000, // ~7.6GB free
+        bfree: 2000000,
+      });
+
+      const result = checkDiskSpaceDuringRecording('/test/path');
+
+      expect(result.allowed).toBe(true);
+    });
+
+    it('should stop recording below the minimum free space', () => {
+      mockStatSync.mockReturnValue({ isDirectory: () => true });
+      mockStatfsSync.mockReturnValue({
+        bsize: 4096,
+        blocks: 10000000,
+        bavail: 100000, // ~390MB free
+        bfree: 100000,
+      });
+
+      const result = checkDiskSpaceDuringRecording('/test/path');
+
+      expect(result.allowed).toBe(false);
+      expect(result.reason).toContain('Disk space low');
+    });
+
+    it('should not use the new-recording size estimate', () => {
+      mockStatSync.mockReturnValue({ isDirectory: () => true });
+      mockStatfsSync.mockReturnValue({
+        bsize: 4096,
+        blocks: 10000000,
+        bavail: 1400000, // ~5.3GB free: above min free, below min free + 1GB estimate
+        bfree: 1400000,
+      });
+
+      expect(checkDiskSpaceForRecording('/test/path').allowed).toBe(false);
+      expect(checkDiskSpaceDuringRecording('/test/path').allowed).toBe(true);
+    });
+
+    it('should stop recording once the total recordings size limit is reached', () => {
+      mockExistsSync.mockReturnValue(true);
+      mockReaddirSync.mockReturnValue(['file1.mp4', 'file2.mp4']);
+      mockStatfsSync.mockReturnValue({
+        bsize: 4096,
+        blocks: 10000000,
+        bavail: 8000000, // Plenty of free space
+        bfree: 8500000,
+      });
+      mockStatSync.mockImplementation((filePath: string) => {
+        if (filePath === '/test/path') return { isDirectory: () => true };
+        return { size: 100 * 1024 * 1024, isFile: () => true };
+      });
+      mockGetDiskLimits.mockReturnValue({
+        minFreeMb: 5000,
+        maxRecordingMb: 0,
+        maxTotalRecordingsMb: 200,
+        maxRecordingDurationHours: 0,
+      });
+
+      const result = checkDiskSpaceDuringRecording('/test/path');
+
+      expect(result.allowed).toBe(false);
+      expect(result.reason).toContain('Reached total recordings size limit');
+    });
+
+    it('should reuse the total recordings size for a short time', () => {
+      vi.useFakeTimers();
+      mockExistsSync.mockReturnValue(true);
+      mockReaddirSync.mockReturnValue(['file1.mp4']);
+      mockStatfsSync.mockReturnValue({
+        bsize: 4096,
+        blocks: 10000000,
+        bavail: 8000000,
+        bfree: 8500000,
+      });
+      mockStatSync.mockImplementation((filePath: string) => {
+        if (filePath === '/test/path') return { isDirectory: () => true };
+        return { size: 100 * 1024 * 1024, isFile: () => true };
+      });
+      mockGetDiskLimits.mockReturnValue({
+        minFreeMb: 5000,
+        maxRecordingMb: 0,
+        maxTotalRecordingsMb: 200,
+        maxRecordingDurationHours: 0,
+      });
+
+      expect(checkDiskSpaceDuringRecording('/test/path').allowed).toBe(true);
+      expect(checkDiskSpaceDuringRecording('/test/path').allowed).toBe(true);
+      expect(mockReaddirSync).toHaveBeenCalledTimes(1);
+
+      // Recordings grew past the limit; picked up once the cached size expires
+      mockReaddirSync.mockReturnValue(['file1.mp4', 'file2.mp4']);
+      vi.advanceTimersByTime(31 * 1000);
+
+      const result = checkDiskSpaceDuringRecording('/test/path');
+      expect(mockReaddirSync).toHaveBeenCalledTimes(2);
+      expect(result.allowed).toBe(false);
+
+      vi.useRealTimers();
+    });
+
+    it('should allow recording when disk info is unavailable', () => {
+      mockStatSync.mockImplementation(() => {
+        throw new Error('Stat failed');
+      });
+
+      expect(checkDiskSpaceDuringRecording('/test/path').allowed).toBe(true);
+    });
+  });
+
   describe('getTotalRecordingsSizeMb', () => {
     it('should calculate total size of recording files', () => {
       mockExistsSync.mockReturnValue(true);
@@ -198,6 +315,24 @@ describe('disk-space utilities', () => {
       expect(result).toBe(300); // 300MB total
     });
 
+    it('should include recordings in per-streamer subdirectories', () => {
+      mockExistsSync.mockReturnValue(true);
+      mockReaddirSync.mockImplementation((dir: string) =>
+        dir === '/test/path' ? ['file1.mp4', 'streamer1'] : ['file2.mp4']
+      );
+      mockStatSync.mockImplementation((filePath: string) => {
+        if (filePath === '/test/path/streamer1') {
+          return { isFile: () => false, isDirectory: () => true };
+        }
+        return { size: 100 * 1024 * 1024, isFile: () => true };
+      });
+
+      const result = getTotalRecordingsSizeMb('/test/path');
+
+      expect(mockReaddirSync).toHaveBeenCalledWith('/test/path/streamer1');
+      expect(result).toBe(200);
+    });
+
     it('should return 0 when directory does not exist', () => {
       mockExistsSync.mockReturnValue(false);
 
@@ -0,0 +1,28 @@
+import { describe, it, expect } from 'vitest';
+import { toTitleSlug } from '../filename';
+
+describe('toTitleSlug', () => {
+  it('should lowercase and dash-separate words', () => {
+    expect(toTitleSlug('Ranked: Day 2!')).toBe('ranked-day-2');
+    expect(toTitleSlug('  New   Topic  ')).toBe('new-topic');
+  });
+
+  it('should strip path separators and other unsafe characters', () => {
+    expect(toTitleSlug('../../etc/passwd')).toBe('etc-passwd');
+    expect(toTitleSlug('a\\b:c*d?"e<f>g|h')).toBe('a-b-c-d-e-f-g-h');
+  });
+
+  it('should fold accents to ASCII', () => {
+    expect(toTitleSlug('Café Über')).toBe('cafe-uber');
+  });
+
+  it('should return an empty string when nothing usable is left', () => {
+    expect(toTitleSlug('🎮🔥')).toBe('');
+    expect(toTitleSlug('')).toBe('');
+  });
+
+  it('should cap the length without a trailing dash', () => {
+    expect(toTitleSlug('a'.repeat(100))).toHaveLength(40);
+    expect(toTitleSlug('abcd efgh', 5)).toBe('abcd');
+  });
+});
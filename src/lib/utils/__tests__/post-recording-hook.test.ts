@@ -0,0 +1,74 @@
+import { describe, it, expect, afterEach } from 'vitest';
+import {
+  quoteShellArg,
+  getPostRecordingCommand,
+  buildPostRecordingCommand,
+} from '../post-recording-hook';
+
+describe('post-recording hook utilities', () => {
+  afterEach(() => {
+    delete process.env.POST_RECORDING_COMMAND;
+  });
+
+  describe('quoteShellArg', () => {
+    it('should wrap values in single quotes', () => {
+      expect(quoteShellArg('/recordings/file.mp4')).toBe(`'/recordings/file.mp4'`);
+    });
+
+    it('should escape embedded single quotes', () => {
+      expect(quoteShellArg(`it's`)).toBe(`'it'\\''s'`);
+    });
+
+    it('should neutralize shell metacharacters', () => {
+      expect(quoteShellArg('a; rm -rf /')).toBe(`'a; rm -rf /'`);
+    });
+  });
+
+  describe('getPostRecordingCommand', () => {
+    it('should return null when not configured', () => {
+      expect(getPostRecordingCommand()).toBeNull();
+    });
+
+    it('should return null for a blank command', () => {
+      process.env.POST_RECORDING_COMMAND = '   ';
+      expect(getPostRecordingCommand()).toBeNull();
+    });
+
+    it('should return the configured command', () => {
+      process.env.POST_RECORDING_COMMAND = 'scp {file} backup:';
+      expect(getPostRecordingCommand()).toBe('scp {file} backup:');
+    });
+  });
+
+  describe('buildPostRecordingCommand', () => {
+    it('should substitute all placeholders', () => {
+      const command = buildPostRecordingCommand('upload {file} --channel {streamer} --seconds {duration}', {
+        file: '/recordings/testuser_2026.mp4',
+        streamer: 'testuser',
+        duration: 3600.7,
+      });
+
+      expect(command).toBe(`upload '/recordings/testuser_2026.mp4' --channel 'testuser' --seconds 3600`);
+    });
+
+    it('should substitute repeated placeholders', () => {
+      const command = buildPostRecordingCommand('cp {file} {file}.bak', {
+        file: '/recordings/a.mp4',
+        streamer: 'testuser',
+        duration: 0,
+      });
+
+      expect(command).toBe(`cp '/recordings/a.mp4' '/recordings/a.mp4'.bak`);
+    });
+
+    it('should leave templates without placeholders unchanged', () => {
+      const command = buildPostRecordingCommand('notify-send done', {
+        file: '/recordings/a.mp4',
+        streamer: 'testuser',
+        duration: 10,
+      });
+
+      expect(command).toBe('notify-send done');
+    });
+  });
+});
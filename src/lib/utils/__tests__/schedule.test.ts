@@ -0,0 +1,84 @@
+import { describe, it, expect } from 'vitest';
+import { parseSchedule, getScheduleWindowEnd, isWithinSchedule, parseStopAt } from '../schedule';
+
+// Local time on a fixed day
+const at = (hours: number, minutes: number = 0) => new Date(2026, 0, 15, hours, minutes);
+
+describe('schedule utilities', () => {
+  describe('parseSchedule', () => {
+    it('should parse a window into minutes', () => {
+      expect(parseSchedule('20:00-22:30')).toEqual({ startMinutes: 1200, endMinutes: 1350 });
+      expect(parseSchedule(' 7:05 - 9:00 ')).toEqual({ startMinutes: 425, endMinutes: 540 });
+    });
+
+    it('should reject malformed or empty windows', () => {
+      expect(parseSchedule('')).toBeNull();
+      expect(parseSchedule('20:00')).toBeNull();
+      expect(parseSchedule('24:00-01:00')).toBeNull();
+      expect(parseSchedule('20:60-22:00')).toBeNull();
+      expect(parseSchedule('20:00-20:00')).toBeNull();
+    });
+  });
+
+  describe('getScheduleWindowEnd', () => {
+    it('should return the end time inside a same-day window', () => {
+      const schedule = parseSchedule('20:00-22:00')!;
+
+      expect(getScheduleWindowEnd(schedule, at(20, 0))).toEqual(at(22, 0));
+      expect(getScheduleWindowEnd(schedule, at(21, 59))).toEqual(at(22, 0));
+    });
+
+    it('should return null outside a same-day window', () => {
+      const schedule = parseSchedule('20:00-22:00')!;
+
+      expect(getScheduleWindowEnd(schedule, at(19, 59))).toBeNull();
+      expect(getScheduleWindowEnd(schedule, at(22, 0))).toBeNull();
+    });
+
+    it('should handle windows that cross midnight', () => {
+      const schedule = parseSchedule('23:00-01:30')!;
+
+      expect(getScheduleWindowEnd(schedule, at(23, 30))).toEqual(new Date(2026, 0, 16, 1, 30));
+      expect(getScheduleWindowEnd(schedule, at(0, 45))).toEqual(at(1, 30));
+      expect(getScheduleWindowEnd(schedule, at(1, 30))).toBeNull();
+      expect(getScheduleWindowEnd(schedule, at(12, 0))).toBeNull();
+    });
+  });
+
+  describe('isWithinSchedule', () => {
+    it('should not restrict recordings without a schedule', () => {
+      expect(isWithinSchedule(null, at(3, 0))).toBe(true);
+      expect(isWithinSchedule('', at(3, 0))).toBe(true);
+    });
+
+    it('should check the configured window', () => {
+      expect(isWithinSchedule('20:00-22:00', at(21, 0))).toBe(true);
+      expect(isWithinSchedule('20:00-22:00', at(3, 0))).toBe(false);
+    });
+  });
+
+  describe('parseStopAt', () => {
+    it('should resolve a clock time to later today', () => {
+      expect(parseStopAt('22:00', at(20, 15))).toEqual(at(22, 0));
+      expect(parseStopAt(' 7:30 ', at(6, 0))).toEqual(at(7, 30));
+    });
+
+    it('should roll a clock time that has passed over to tomorrow', () => {
+      expect(parseStopAt('01:00', at(23, 0))).toEqual(new Date(2026, 0, 16, 1, 0));
+      expect(parseStopAt('20:15', at(20, 15))).toEqual(new Date(2026, 0, 16, 20, 15));
+    });
+
+    it('should accept a full date-time as given', () => {
+      expect(parseStopAt('2026-01-15T22:00', at(20, 0))).toEqual(at(22, 0));
+      expect(parseStopAt('2026-01-14T22:00', at(20, 0))).toEqual(new Date(2026, 0, 14, 22, 0));
+    });
+
+    it('should reject malformed values', () => {
+      expect(parseStopAt('')).toBeNull();
+      expect(parseStopAt('25:00')).toBeNull();
+      expect(parseStopAt('10pm')).toBeNull();
+      expect(parseStopAt('2026-01-15')).toBeNull();
+      expect(parseStopAt('2026-13-45T99:99')).toBeNull();
+    });
+  });
+});
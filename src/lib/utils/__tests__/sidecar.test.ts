@@ -0,0 +1,61 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest';
+import fs from 'fs';
+import { getSidecarPath, writeMetadataSidecar, RecordingMetadata } from '../sidecar';
+
+vi.mock('fs', () => ({
+  default: {
+    writeFileSync: vi.fn(),
+    renameSync: vi.fn(),
+  },
+}));
+
+const metadata: RecordingMetadata = {
+  recordingId: 1,
+  streamer: 'testuser',
+  title: 'Test Stream',
+  category: 'Just Chatting',
+  quality: '1080p60',
+  url: 'https://twitch.tv/testuser',
+  file: '/recordings/testuser_2026.mp4',
+  startedAt: '2026-01-01T00:00:00.000Z',
+  endedAt: '2026-01-01T01:00:00.000Z',
+  durationSeconds: 3600,
+  fileSizeBytes: 1024,
+  status: 'completed',
+  exitCode: 0,
+};
+
+describe('sidecar utilities', () => {
+  beforeEach(() => {
+    vi.mocked(fs.writeFileSync).mockClear();
+    vi.mocked(fs.renameSync).mockClear();
+  });
+
+  describe('getSidecarPath', () => {
+    it('should replace the video extension with .json', () => {
+      expect(getSidecarPath('/recordings/testuser_2026.mp4')).toBe('/recordings/testuser_2026.json');
+    });
+  });
+
+  describe('writeMetadataSidecar', () => {
+    it('should write JSON to a temp file and rename it into place', () => {
+      const sidecarPath = writeMetadataSidecar('/recordings/testuser_2026.mp4', metadata);
+
+      expect(sidecarPath).toBe('/recordings/testuser_2026.json');
+      expect(fs.writeFileSync).toHaveBeenCalledWith('/recordings/testuser_2026.json.tmp', expect.any(String));
+      expect(fs.renameSync).toHaveBeenCalledWith('/recordings/testuser_2026.json.tmp', '/recordings/testuser_2026.json');
+
+      const written = vi.mocked(fs.writeFileSync).mock.calls[0][1] as string;
+      expect(JSON.parse(written)).toEqual(metadata);
+    });
+
+    it('should not rename when the write fails', () => {
+      vi.mocked(fs.writeFileSync).mockImplementationOnce(() => {
+        throw new Error('ENOSPC');
+      });
+
+      expect(() => writeMetadataSidecar('/recordings/testuser_2026.mp4', metadata)).toThrow('ENOSPC');
+      expect(fs.renameSync).not.toHaveBeenCalled();
+    });
+  });
+});
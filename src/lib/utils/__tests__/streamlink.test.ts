@@ -0,0 +1,89 @@
+import { describe, it, expect, afterEach } from 'vitest';
+import { parseSelectedStream, parseStreamNotFound, splitArgs, getExtraStreamlinkArgs } from '../streamlink';
+
+describe('streamlink utilities', () => {
+  describe('parseSelectedStream', () => {
+    it('should extract the opened stream name', () => {
+      expect(parseSelectedStream('[cli][info] Opening stream: 1080p60 (hls)')).toBe('1080p60');
+    });
+
+    it('should find the line within multi-line output', () => {
+      const output = [
+        '[cli][info] Found matching plugin twitch for URL https://twitch.tv/testuser',
+        '[cli][info] Available streams: audio_only, 160p (worst), 720p60, 1080p60 (best)',
+        '[cli][info] Opening stream: 720p60 (hls)',
+      ].join('\n');
+
+      expect(parseSelectedStream(output)).toBe('720p60');
+    });
+
+    it('should return null when no stream was opened', () => {
+      expect(parseSelectedStream('[cli][info] Available streams: 720p60, 1080p60 (best)')).toBeNull();
+      expect(parseSelectedStream('')).toBeNull();
+    });
+  });
+
+  describe('parseStreamNotFound', () => {
+    it('should extract the requested and available streams', () => {
+      const output = "error: The specified stream(s) '720p60' could not be found.\n" +
+        '       Available streams: audio_only, 160p (worst), 480p, 1080p60 (best)';
+
+      expect(parseStreamNotFound(output)).toEqual({
+        requested: '720p60',
+        available: ['audio_only', '160p', '480p', '1080p60'],
+      });
+    });
+
+    it('should handle a source-only channel', () => {
+      const output = "error: The specified stream(s) '720p60' could not be found.\n" +
+        '       Available streams: audio_only, 1080p60 (worst, best)';
+
+      expect(parseStreamNotFound(output)?.available).toEqual(['audio_only', '1080p60']);
+    });
+
+    it('should return null for other output', () => {
+      expect(parseStreamNotFound('[cli][info] Opening stream: 1080p60 (hls)')).toBeNull();
+    });
+  });
+
+  describe('splitArgs', () => {
+    it('should split on whitespace', () => {
+      expect(splitArgs('--http-no-ssl-verify  --retry-open 3')).toEqual(['--http-no-ssl-verify', '--retry-open', '3']);
+    });
+
+    it('should keep quoted values together', () => {
+      expect(splitArgs(`--http-header "User-Agent=My Agent" --http-proxy 'http://proxy:8080'`))
+        .toEqual(['--http-header', 'User-Agent=My Agent', '--http-proxy', 'http://proxy:8080']);
+    });
+
+    it('should keep a quoted auth header with a space as one argument', () => {
+      expect(splitArgs('"--twitch-api-header=Authorization=OAuth abc123"'))
+        .toEqual(['--twitch-api-header=Authorization=OAuth abc123']);
+      expect(splitArgs('--twitch-api-header=Authorization=OAuth abc123'))
+        .toEqual(['--twitch-api-header=Authorization=OAuth', 'abc123']);
+    });
+
+    it('should handle escapes and empty quoted values', () => {
+      expect(splitArgs(`--title My\\ Stream "a \\"b\\"" ''`)).toEqual(['--title', 'My Stream', 'a "b"', '']);
+    });
+
+    it('should return an empty array for blank input', () => {
+      expect(splitArgs('   ')).toEqual([]);
+    });
+  });
+
+  describe('getExtraStreamlinkArgs', () => {
+    afterEach(() => {
+      delete process.env.STREAMLINK_ARGS;
+    });
+
+    it('should return an empty array when not configured', () => {
+      expect(getExtraStreamlinkArgs()).toEqual([]);
+    });
+
+    it('should parse the configured arguments', () => {
+      process.env.STREAMLINK_ARGS = '--http-no-ssl-verify --twitch-api-header "Authorization=OAuth abc"';
+      expect(getExtraStreamlinkArgs()).toEqual(['--http-no-ssl-verify', '--twitch-api-header', 'Authorization=OAuth abc']);
+    });
+  });
+});
@@ -0,0 +1,34 @@
+import { describe, it, expect } from 'vitest';
+import { parseChatMessage, formatChatLine } from '../twitch-chat';
+
+describe('twitch chat utilities', () => {
+  describe('parseChatMessage', () => {
+    it('should parse a chat message', () => {
+      expect(parseChatMessage(':viewer1!viewer1@viewer1.tmi.twitch.tv PRIVMSG #testuser :hello chat'))
+        .toEqual({ username: 'viewer1', message: 'hello chat' });
+    });
+
+    it('should keep colons inside the message', () => {
+      expect(parseChatMessage(':viewer1!viewer1@viewer1.tmi.twitch.tv PRIVMSG #testuser :time: 12:30'))
+        .toEqual({ username: 'viewer1', message: 'time: 12:30' });
+    });
+
+    it('should accept lines with IRCv3 tags', () => {
+      expect(parseChatMessage('@badges=;color= :viewer1!viewer1@viewer1.tmi.twitch.tv PRIVMSG #testuser :hi'))
+        .toEqual({ username: 'viewer1', message: 'hi' });
+    });
+
+    it('should ignore non-message lines', () => {
+      expect(parseChatMessage(':tmi.twitch.tv 001 justinfan12345 :Welcome, GLHF!')).toBeNull();
+      expect(parseChatMessage(':justinfan12345!justinfan12345@justinfan12345.tmi.twitch.tv JOIN #testuser')).toBeNull();
+      expect(parseChatMessage('PING :tmi.twitch.tv')).toBeNull();
+    });
+  });
+
+  describe('formatChatLine', () => {
+    it('should prefix the message with an ISO timestamp and username', () => {
+      expect(formatChatLine(new Date('2026-01-01T12:00:00.000Z'), 'viewer1', 'hello'))
+        .toBe('[2026-01-01T12:00:00.000Z] viewer1: hello');
+    });
+  });
+});
@@ -0,0 +1,51 @@
+import { describe, it, expect } from 'vitest';
+import { parseTwitchUsername } from '../twitch-username';
+
+describe('parseTwitchUsername', () => {
+  it('should accept a bare login', () => {
+    expect(parseTwitchUsername('shroud')).toBe('shroud');
+    expect(parseTwitchUsername('  Test_User123 ')).toBe('test_user123');
+  });
+
+  it('should strip a leading @', () => {
+    expect(parseTwitchUsername('@shroud')).toBe('shroud');
+  });
+
+  it('should extract the login from channel URLs', () => {
+    expect(parseTwitchUsername('https://www.twitch.tv/shroud')).toBe('shroud');
+    expect(parseTwitchUsername('http://twitch.tv/Shroud/')).toBe('shroud');
+    expect(parseTwitchUsername('twitch.tv/shroud')).toBe('shroud');
+    expect(parseTwitchUsername('https://m.twitch.tv/shroud/videos?filter=archives')).toBe('shroud');
+  });
+
+  it('should reject URLs for other hosts', () => {
+    expect(parseTwitchUsername('https://nottwitch.tv/shroud')).toBeNull();
+    expect(parseTwitchUsername('https://example.com/twitch.tv/shroud')).toBeNull();
+    expect(parseTwitchUsername('https://clips.twitch.tv/SomeClipSlug')).toBeNull();
+    expect(parseTwitchUsername('dashboard.twitch.tv/shroud')).toBeNull();
+  });
+
+  it('should reject Twitch pages that are not channels', () => {
+    expect(parseTwitchUsername('https://www.twitch.tv/videos/123456789')).toBeNull();
+    expect(parseTwitchUsername('twitch.tv/directory/category/just-chatting')).toBeNull();
+    expect(parseTwitchUsername('https://www.twitch.tv/settings')).toBeNull();
+    expect(parseTwitchUsername('https://www.twitch.tv/p/en/legal/terms-of-service')).toBeNull();
+    expect(parseTwitchUsername('https://www.twitch.tv/downloads')).toBeNull();
+  });
+
+  it('should reject URLs without a channel', () => {
+    expect(parseTwitchUsername('https://www.twitch.tv/')).toBeNull();
+  });
+
+  it('should reject characters outside the Twitch login charset', () => {
+    expect(parseTwitchUsername('')).toBeNull();
+    expect(parseTwitchUsername('bad name')).toBeNull();
+    expect(parseTwitchUsername('bad-name')).toBeNull();
+    expect(parseTwitchUsername('../etc/passwd')).toBeNull();
+  });
+
+  it('should reject logins longer than 25 characters', () => {
+    expect(parseTwitchUsername('a'.repeat(25))).toBe('a'.repeat(25));
+    expect(parseTwitchUsername('a'.repeat(26))).toBeNull();
+  });
+});
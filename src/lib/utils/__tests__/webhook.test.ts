@@ -0,0 +1,91 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+  isValidWebhookUrl,
+  formatWebhookMessage,
+  sendWebhookNotification,
+  WebhookPayload,
+} from '../webhook';
+
+const basePayload: WebhookPayload = {
+  event: 'recording.ended',
+  recordingId: 1,
+  streamer: 'testuser',
+  quality: '1080p60',
+  file: 'recordings/testuser_2026.mp4',
+  fileSizeBytes: 50 * 1024 * 1024,
+  durationSeconds: 3600,
+  exitCode: 0,
+  timestamp: '2026-01-01T00:00:00.000Z',
+};
+
+describe('webhook utilities', () => {
+  describe('isValidWebhookUrl', () => {
+    it('should accept http and https URLs', () => {
+      expect(isValidWebhookUrl('https://discord.com/api/webhooks/1/abc')).toBe(true);
+      expect(isValidWebhookUrl('http://localhost:8080/hook')).toBe(true);
+    });
+
+    it('should reject other protocols and malformed values', () => {
+      expect(isValidWebhookUrl('ftp://example.com/hook')).toBe(false);
+      expect(isValidWebhookUrl('example.com/hook')).toBe(false);
+      expect(isValidWebhookUrl('')).toBe(false);
+    });
+  });
+
+  describe('formatWebhookMessage', () => {
+    it('should describe a started recording', () => {
+      expect(formatWebhookMessage({ ...basePayload, event: 'recording.started' }))
+        .toBe('Started recording testuser (1080p60)');
+    });
+
+    it('should include summary stats for an ended recording', () => {
+      expect(formatWebhookMessage(basePayload))
+        .toBe('Finished recording testuser: 3600s, 50.0 MB');
+    });
+  });
+
+  describe('sendWebhookNotification', () => {
+    let fetchMock: ReturnType<typeof vi.fn>;
+
+    beforeEach(() => {
+      fetchMock = vi.fn(async () => ({ ok: true, status: 200 }));
+      vi.stubGlobal('fetch', fetchMock);
+      vi.spyOn(console, 'error').mockImplementation(() => {});
+    });
+
+    afterEach(() => {
+      vi.unstubAllGlobals();
+      vi.restoreAllMocks();
+    });
+
+    it('should POST the payload as JSON', async () => {
+      const sent = await sendWebhookNotification('https://example.com/hook', basePayload);
+
+      expect(sent).toBe(true);
+      expect(fetchMock).toHaveBeenCalledWith(
+        'https://example.com/hook',
+        expect.objectContaining({
+          method: 'POST',
+          headers: { 'Content-Type': 'application/json' },
+        })
+      );
+
+      const body = JSON.parse(fetchMock.mock.calls[0][1].body);
+      expect(body).toMatchObject(basePayload);
+      expect(body.content).toBe('Finished recording testuser: 3600s, 50.0 MB');
+      expect(body.text).toBe(body.content);
+    });
+
+    it('should return false for a non-2xx response', async () => {
+      fetchMock.mockResolvedValue({ ok: false, status: 500 });
+
+      await expect(sendWebhookNotification('https://example.com/hook', basePayload)).resolves.toBe(false);
+    });
+
+    it('should return false instead of throwing on network errors', async () => {
+      fetchMock.mockRejectedValue(new Error('connection refused'));
+
+      await expect(sendWebhookNotification('https://example.com/hook', basePayload)).resolves.toBe(false);
+    });
+  });
+});
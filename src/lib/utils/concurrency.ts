@@ -0,0 +1,28 @@
+/**
+ * Run an async worker over items with at most `limit` in flight at once.
+ * Each worker waits a random 0-`jitterMs` delay before starting so a batch
+ * doesn't fire its requests in lockstep. Worker errors are not caught here.
+ */
+export async function runWithConcurrency<T>(
+  items: T[],
+  limit: number,
+  worker: (item: T) => Promise<void>,
+  jitterMs: number = 0
+): Promise<void> {
+  let next = 0;
+
+  const runNext = async (): Promise<void> => {
+    while (next < items.length) {
+      const item = items[next++];
+
+      if (jitterMs > 0) {
+        await new Promise(resolve => setTimeout(resolve, Math.random() * jitterMs));
+      }
+
+      await worker(item);
+    }
+  };
+
+  const runners = Array.from({ length: Math.min(Math.max(limit, 1), items.length) }, runNext);
+  await Promise.all(runners);
+}
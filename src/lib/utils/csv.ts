@@ -0,0 +1,32 @@
+// Spreadsheets evaluate text cells starting with these as formulas
+const FORMULA_PREFIX = /^[=+\-@\t\r]/;
+
+/**
+ * Quote a value for CSV if it contains a delimiter, quote or line break.
+ * Text that a spreadsheet would run as a formula is prefixed with '.
+ * Null and undefined become empty fields.
+ */
+export function escapeCsvField(value: unknown): string {
+  if (value === null || value === undefined) return '';
+
+  let text = String(value);
+  // Numbers are left alone so negative values stay numeric
+  if (typeof value === 'string' && FORMULA_PREFIX.test(text)) {
+    text = `'${text}`;
+  }
+  if (/[",\r\n]/.test(text)) {
+    return `"${text.replace(/"/g, '""')}"`;
+  }
+  return text;
+}
+
+/**
+ * Build a CSV document with a header row from the given columns
+ */
+export function toCsv<T extends object>(columns: (keyof T & string)[], rows: T[]): string {
+  const lines = [columns.map(escapeCsvField).join(',')];
+  for (const row of rows) {
+    lines.push(columns.map(column => escapeCsvField(row[column])).join(','));
+  }
+  return `${lines.join('\r\n')}\r\n`;
+}
@@ -17,6 +17,11 @@ export interface DiskCheckResult {
   usedPercentage: number;
 }
 
+// Walking the recordings tree is expensive and every active recording checks
+// the total every few seconds, so the in-recording check reuses a recent result
+const RECORDINGS_SIZE_CACHE_TTL_MS = 30 * 1000;
+const recordingsSizeCache = new Map<string, { sizeMb: number; expiresAt: number }>();
+
 /**
  * Get disk space information for a path
  */
@@ -108,6 +113,45 @@ export function checkDiskSpaceForRecording(
   };
 }
 
+/**
+ * Check that an in-progress recording can keep writing without dropping
+ * below the minimum free space or growing past the total recordings size limit.
+ * Unknown disk info (statfs unavailable) is allowed.
+ */
+export function checkDiskSpaceDuringRecording(recordingsDir: string): DiskCheckResult {
+  const info = getDiskSpaceInfo(recordingsDir);
+  const freeSpaceMb = Math.floor(info.available / (1024 * 1024));
+
+  const limits = SettingsModel.getDiskLimits();
+
+  if (info.total > 0 && limits.minFreeMb > 0 && freeSpaceMb < limits.minFreeMb) {
+    return {
+      allowed: false,
+      reason: `Disk space low. Free: ${freeSpaceMb}MB, Minimum: ${limits.minFreeMb}MB`,
+      freeSpaceMb,
+      usedPercentage: info.usedPercentage,
+    };
+  }
+
+  if (limits.maxTotalRecordingsMb > 0) {
+    const currentSizeMb = getCachedTotalRecordingsSizeMb(recordingsDir);
+    if (currentSizeMb >= limits.maxTotalRecordingsMb) {
+      return {
+        allowed: false,
+        reason: `Reached total recordings size limit. Current: ${currentSizeMb}MB, Limit: ${limits.maxTotalRecordingsMb}MB`,
+        freeSpaceMb,
+        usedPercentage: info.usedPercentage,
+      };
+    }
+  }
+
+  return {
+    allowed: true,
+    freeSpaceMb,
+    usedPercentage: info.usedPercentage,
+  };
+}
+
 /**
  * Get total size of all recordings in directory (in MB)
  */
@@ -117,28 +161,59 @@ export function getTotalRecordingsSizeMb(recordingsDir: string): number {
       return 0;
     }
     
-    const files = fs.readdirSync(recordingsDir);
-    let totalBytes = 0;
-    
-    for (const file of files) {
-      const filePath = path.join(recordingsDir, file);
-      try {
-        const stats = fs.statSync(filePath);
-        if (stats.isFile()) {
-          totalBytes += stats.size;
-        }
-      } catch {
-        // Skip files we can't stat
-      }
-    }
-    
-    return Math.floor(totalBytes / (1024 * 1024));
+    return Math.floor(getDirectorySizeBytes(recordingsDir) / (1024 * 1024));
   } catch (error) {
     console.error('Error calculating recordings size:', error);
     return 0;
   }
 }
 
+/**
+ * Get the total recordings size, recomputing it at most once per cache TTL
+ */
+function getCachedTotalRecordingsSizeMb(recordingsDir: string): number {
+  const now = Date.now();
+  const cached = recordingsSizeCache.get(recordingsDir);
+  if (cached && cached.expiresAt > now) {
+    return cached.sizeMb;
+  }
+
+  const sizeMb = getTotalRecordingsSizeMb(recordingsDir);
+  recordingsSizeCache.set(recordingsDir, { sizeMb, expiresAt: now + RECORDINGS_SIZE_CACHE_TTL_MS });
+  return sizeMb;
+}
+
+/**
+ * Forget cached recordings sizes (used by tests)
+ */
+export function clearRecordingsSizeCache(): void {
+  recordingsSizeCache.clear();
+}
+
+/**
+ * Sum the size of all files in a directory, including per-streamer subdirectories
+ */
+function getDirectorySizeBytes(dirPath: string): number {
+  const files = fs.readdirSync(dirPath);
+  let totalBytes = 0;
+  
+  for (const file of files) {
+    const filePath = path.join(dirPath, file);
+    try {
+      const stats = fs.statSync(filePath);
+      if (stats.isFile()) {
+        totalBytes += stats.size;
+      } else if (stats.isDirectory()) {
+        totalBytes += getDirectorySizeBytes(filePath);
+      }
+    } catch {
+      // Skip files we can't stat
+    }
+  }
+  
+  return totalBytes;
+}
+
 /**
  * Format bytes to human-readable string
  */
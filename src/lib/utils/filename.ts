@@ -0,0 +1,18 @@
+// Keeps title-based filenames well under filesystem name limits
+const MAX_SLUG_LENGTH = 40;
+
+/**
+ * Turn a stream title into a filename-safe slug, e.g. "Ranked: Day 2!" -> "ranked-day-2".
+ * Accents are folded to ASCII; anything else outside [a-z0-9] becomes a single dash.
+ * Returns an empty string when nothing usable is left (e.g. an emoji-only title).
+ */
+export function toTitleSlug(title: string, maxLength: number = MAX_SLUG_LENGTH): string {
+  return title
+    .normalize('NFKD')
+    .replace(/[\u0300-\u036f]/g, '')
+    .toLowerCase()
+    .replace(/[^a-z0-9]+/g, '-')
+    .replace(/^-+|-+$/g, '')
+    .slice(0, maxLength)
+    .replace(/-+$/, '');
+}
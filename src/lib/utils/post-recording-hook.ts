@@ -0,0 +1,32 @@
+export interface PostRecordingHookValues {
+  file: string;
+  streamer: string;
+  duration: number;
+}
+
+/**
+ * Quote a value for safe use as a single POSIX shell argument
+ */
+export function quoteShellArg(value: string): string {
+  return `'${value.replace(/'/g, `'\\''`)}'`;
+}
+
+/**
+ * Get the post-recording command template from the environment
+ * Returns null if no command is configured
+ */
+export function getPostRecordingCommand(): string | null {
+  const command = process.env.POST_RECORDING_COMMAND?.trim();
+  return command ? command : null;
+}
+
+/**
+ * Build the shell command for a finished recording by filling in the
+ * {file}, {streamer} and {duration} placeholders. Values are shell-quoted.
+ */
+export function buildPostRecordingCommand(template: string, values: PostRecordingHookValues): string {
+  return template
+    .replace(/\{file\}/g, quoteShellArg(values.file))
+    .replace(/\{streamer\}/g, quoteShellArg(values.streamer))
+    .replace(/\{duration\}/g, String(Math.floor(values.duration)));
+}
@@ -0,0 +1,78 @@
+// A daily recording window in local time, e.g. "20:00-22:00" or "23:00-01:30"
+const SCHEDULE_PATTERN = /^([01]?\d|2[0-3]):([0-5]\d)\s*-\s*([01]?\d|2[0-3]):([0-5]\d)$/;
+
+export interface RecordingSchedule {
+  startMinutes: number;
+  endMinutes: number;
+}
+
+/**
+ * Parse an "HH:MM-HH:MM" schedule into minutes since local midnight.
+ * Windows that end earlier than they start cross midnight.
+ * Returns null for malformed or zero-length windows.
+ */
+export function parseSchedule(value: string): RecordingSchedule | null {
+  const match = value.trim().match(SCHEDULE_PATTERN);
+  if (!match) return null;
+
+  const startMinutes = parseInt(match[1], 10) * 60 + parseInt(match[2], 10);
+  const endMinutes = parseInt(match[3], 10) * 60 + parseInt(match[4], 10);
+  if (startMinutes === endMinutes) return null;
+
+  return { startMinutes, endMinutes };
+}
+
+/**
+ * Get when the current window ends, or null if `now` is outside the window
+ */
+export function getScheduleWindowEnd(schedule: RecordingSchedule, now: Date = new Date()): Date | null {
+  const minutes = now.getHours() * 60 + now.getMinutes();
+  const crossesMidnight = schedule.endMinutes < schedule.startMinutes;
+
+  const inWindow = crossesMidnight
+    ? minutes >= schedule.startMinutes || minutes < schedule.endMinutes
+    : minutes >= schedule.startMinutes && minutes < schedule.endMinutes;
+  if (!inWindow) return null;
+
+  const end = new Date(now);
+  end.setHours(Math.floor(schedule.endMinutes / 60), schedule.endMinutes % 60, 0, 0);
+  if (end <= now) {
+    end.setDate(end.getDate() + 1);
+  }
+  return end;
+}
+
+/**
+ * Check whether `now` falls inside a schedule string.
+ * A missing or unparseable schedule places no restriction.
+ */
+export function isWithinSchedule(value: string | null | undefined, now: Date = new Date()): boolean {
+  const schedule = value ? parseSchedule(value) : null;
+  if (!schedule) return true;
+  return getScheduleWindowEnd(schedule, now) !== null;
+}
+
+const CLOCK_TIME_PATTERN = /^([01]?\d|2[0-3]):([0-5]\d)$/;
+
+/**
+ * Parse a stop time for a recording. "HH:MM" is the next time the local clock
+ * reads that (later today, or tomorrow if it has passed); anything else must be
+ * an ISO date-time such as "2026-01-15T22:00". Returns null if malformed.
+ */
+export function parseStopAt(value: string, now: Date = new Date()): Date | null {
+  const trimmed = value.trim();
+
+  const clock = trimmed.match(CLOCK_TIME_PATTERN);
+  if (clock) {
+    const stopAt = new Date(now);
+    stopAt.setHours(parseInt(clock[1], 10), parseInt(clock[2], 10), 0, 0);
+    if (stopAt <= now) {
+      stopAt.setDate(stopAt.getDate() + 1);
+    }
+    return stopAt;
+  }
+
+  if (!/^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}/.test(trimmed)) return null;
+  const stopAt = new Date(trimmed);
+  return isNaN(stopAt.getTime()) ? null : stopAt;
+}
@@ -0,0 +1,40 @@
+import fs from 'fs';
+import path from 'path';
+
+export interface RecordingMetadata {
+  recordingId: number;
+  streamer: string;
+  title: string | null;
+  category: string | null;
+  quality: string;
+  url: string;
+  file: string;
+  startedAt: string;
+  endedAt: string;
+  durationSeconds: number;
+  fileSizeBytes: number;
+  status: string;
+  exitCode: number | null;
+}
+
+/**
+ * Get the sidecar path for a recording, e.g. user_<timestamp>.mp4 -> user_<timestamp>.json
+ */
+export function getSidecarPath(videoPath: string): string {
+  const parsed = path.parse(videoPath);
+  return path.join(parsed.dir, `${parsed.name}.json`);
+}
+
+/**
+ * Write recording metadata next to the video as JSON.
+ * Written to a temporary file and renamed so readers never see a partial file.
+ */
+export function writeMetadataSidecar(videoPath: string, metadata: RecordingMetadata): string {
+  const sidecarPath = getSidecarPath(videoPath);
+  const tempPath = `${sidecarPath}.tmp`;
+
+  fs.writeFileSync(tempPath, `${JSON.stringify(metadata, null, 2)}\n`);
+  fs.renameSync(tempPath, sidecarPath);
+
+  return sidecarPath;
+}
@@ -0,0 +1,87 @@
+/**
+ * Extract the stream name streamlink reports when it opens a stream,
+ * e.g. "[cli][info] Opening stream: 1080p60 (hls)" -> "1080p60".
+ * Returns null if the output doesn't contain that line.
+ */
+export function parseSelectedStream(output: string): string | null {
+  const match = output.match(/Opening stream:\s*(\S+)/);
+  return match ? match[1] : null;
+}
+
+/**
+ * Parse streamlink's error for a quality the channel doesn't offer, e.g.
+ * "error: The specified stream(s) '720p60' could not be found.
+ *        Available streams: audio_only, 1080p60 (worst, best)"
+ * Available streams are returned without the worst/best aliases.
+ * Returns null if the output doesn't contain that error.
+ */
+export function parseStreamNotFound(output: string): { requested: string; available: string[] } | null {
+  const match = output.match(/specified stream\(s\) '([^']*)' could not be found/);
+  if (!match) return null;
+
+  const availableMatch = output.match(/Available streams:\s*(.+)/);
+  const available = availableMatch
+    ? availableMatch[1]
+        .replace(/\([^)]*\)/g, '')
+        .split(',')
+        .map(name => name.trim())
+        .filter(Boolean)
+    : [];
+
+  return { requested: match[1], available };
+}
+
+/**
+ * Split a command-line string into arguments, honoring single quotes,
+ * double quotes and backslash escapes. No shell expansion is performed.
+ */
+export function splitArgs(value: string): string[] {
+  const args: string[] = [];
+  let current = '';
+  let inArg = false;
+  let quote: '"' | "'" | null = null;
+
+  for (let i = 0; i < value.length; i++) {
+    const char = value[i];
+
+    if (quote) {
+      if (char === quote) {
+        quote = null;
+      } else if (char === '\\' && quote === '"' && i + 1 < value.length) {
+        current += value[++i];
+      } else {
+        current += char;
+      }
+    } else if (char === '"' || char === "'") {
+      quote = char;
+      inArg = true;
+    } else if (char === '\\' && i + 1 < value.length) {
+      current += value[++i];
+      inArg = true;
+    } else if (/\s/.test(char)) {
+      if (inArg) {
+        args.push(current);
+        current = '';
+        inArg = false;
+      }
+    } else {
+      current += char;
+      inArg = true;
+    }
+  }
+
+  if (inArg) {
+    args.push(current);
+  }
+
+  return args;
+}
+
+/**
+ * Get extra streamlink options from the STREAMLINK_ARGS environment variable
+ * Returns an empty array if none are configured
+ */
+export function getExtraStreamlinkArgs(): string[] {
+  const value = process.env.STREAMLINK_ARGS?.trim();
+  return value ? splitArgs(value) : [];
+}
@@ -0,0 +1,21 @@
+export interface ChatMessage {
+  username: string;
+  message: string;
+}
+
+/**
+ * Parse a Twitch IRC PRIVMSG line, e.g.
+ * ":nick!nick@nick.tmi.twitch.tv PRIVMSG #channel :hello" -> { username: 'nick', message: 'hello' }
+ * Returns null for any other kind of line.
+ */
+export function parseChatMessage(line: string): ChatMessage | null {
+  const match = line.match(/^(?:@\S+ )?:([^!\s]+)!\S+ PRIVMSG #\S+ :(.*)$/);
+  return match ? { username: match[1], message: match[2] } : null;
+}
+
+/**
+ * Format a chat message as a timestamped transcript line
+ */
+export function formatChatLine(timestamp: Date, username: string, message: string): string {
+  return `[${timestamp.toISOString()}] ${username}: ${message}`;
+}
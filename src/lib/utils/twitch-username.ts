@@ -0,0 +1,37 @@
+// Twitch logins are letters, digits and underscores, at most 25 characters
+const TWITCH_LOGIN_PATTERN = /^[a-zA-Z0-9_]{1,25}$/;
+
+// Hosts that serve channel pages; clips.twitch.tv, dashboard.twitch.tv etc. don't
+const CHANNEL_HOSTS = new Set(['twitch.tv', 'www.twitch.tv', 'm.twitch.tv']);
+
+// First path segments that are Twitch pages rather than channels
+const RESERVED_PATHS = new Set([
+  'directory', 'downloads', 'friends', 'inventory', 'jobs', 'moderator', 'p',
+  'popout', 'prime', 'search', 'settings', 'store', 'subscriptions', 'turbo',
+  'u', 'videos', 'wallet',
+]);
+
+/**
+ * Extract a Twitch login from a bare username or a channel URL
+ * (e.g. "shroud", "@shroud", "twitch.tv/shroud", "https://www.twitch.tv/shroud/videos").
+ * Returns the lowercased login, or null if the input isn't a valid login or channel URL.
+ */
+export function parseTwitchUsername(input: string): string | null {
+  let value = input.trim();
+
+  if (/twitch\.tv/i.test(value)) {
+    try {
+      const url = new URL(/^https?:\/\//i.test(value) ? value : `https://${value}`);
+      const host = url.hostname.toLowerCase();
+      if (!CHANNEL_HOSTS.has(host)) return null;
+      value = url.pathname.split('/').filter(Boolean)[0] ?? '';
+      if (RESERVED_PATHS.has(value.toLowerCase())) return null;
+    } catch {
+      return null;
+    }
+  } else if (value.startsWith('@')) {
+    value = value.slice(1);
+  }
+
+  return TWITCH_LOGIN_PATTERN.test(value) ? value.toLowerCase() : null;
+}
@@ -0,0 +1,72 @@
+export type WebhookEvent = 'recording.started' | 'recording.ended';
+
+export interface WebhookPayload {
+  event: WebhookEvent;
+  recordingId: number;
+  streamer: string;
+  quality: string | null;
+  // e.g. "recordings/<streamer>/<file>.mp4"
+  file: string;
+  fileSizeBytes: number;
+  durationSeconds: number;
+  exitCode?: number | null;
+  timestamp: string;
+}
+
+const WEBHOOK_TIMEOUT_MS = 5000;
+
+/**
+ * Check that a webhook URL is an absolute http(s) URL
+ */
+export function isValidWebhookUrl(value: string): boolean {
+  try {
+    const url = new URL(value);
+    return url.protocol === 'http:' || url.protocol === 'https:';
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Build a one-line human readable summary of a recording event
+ */
+export function formatWebhookMessage(payload: WebhookPayload): string {
+  if (payload.event === 'recording.started') {
+    return `Started recording ${payload.streamer} (${payload.quality || 'best'})`;
+  }
+
+  const sizeMb = (payload.fileSizeBytes / (1024 * 1024)).toFixed(1);
+  return `Finished recording ${payload.streamer}: ${payload.durationSeconds}s, ${sizeMb} MB`;
+}
+
+/**
+ * POST a recording event to a webhook as JSON.
+ * The summary is included as `content` and `text` so Discord and Slack
+ * webhooks can display it directly. Never throws; returns false on failure.
+ */
+export async function sendWebhookNotification(
+  url: string,
+  payload: WebhookPayload,
+  timeoutMs: number = WEBHOOK_TIMEOUT_MS
+): Promise<boolean> {
+  const message = formatWebhookMessage(payload);
+
+  try {
+    const response = await fetch(url, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      body: JSON.stringify({ ...payload, content: message, text: message }),
+      signal: AbortSignal.timeout(timeoutMs),
+    });
+
+    if (!response.ok) {
+      console.error(`Webhook notification failed with status ${response.status}`);
+      return false;
+    }
+
+    return true;
+  } catch (error) {
+    console.error('Failed to send webhook notification:', error);
+    return false;
+  }
+}
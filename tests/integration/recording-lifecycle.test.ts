@@ -17,9 +17,10 @@ vi.mock('child_process', () => ({
 // Mock fs
 vi.mock('fs', () => ({
   default: {
-    existsSync: vi.fn(() => true),
+    // Directories exist; recording files don't, so new filenames are free
+    existsSync: vi.fn((p: string) => !String(p).endsWith('.mp4')),
     mkdirSync: vi.fn(),
-    statSync: vi.fn(() => ({ size: 1024 * 1024 * 10 })),
+    statSync: vi.fn(() => ({ size: 1024 * 1024 * 10, isDirectory: () => true })),
     statfsSync: vi.fn(() => ({
       bsize: 4096,
       blocks: 10000000,
@@ -33,6 +34,7 @@ vi.mock('fs', () => ({
 // Mock disk-space module
 vi.mock('@/lib/utils/disk-space', () => ({
   checkDiskSpaceForRecording: vi.fn(() => ({ allowed: true, freeSpaceMb: 10000, usedPercentage: 50 })),
+  checkDiskSpaceDuringRecording: vi.fn(() => ({ allowed: true, freeSpaceMb: 10000, usedPercentage: 50 })),
   getDiskSpaceStatus: vi.fn(() => ({ total: '100 GB', used: '50 GB', free: '50 GB', usedPercentage: 50, status: 'ok' as const })),
   getTotalRecordingsSizeMb: vi.fn(() => 0),
   formatBytes: vi.fn((bytes: number) => `${bytes} B`),
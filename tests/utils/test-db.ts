@@ -88,6 +88,7 @@ function createTables(db: DatabaseType): void {
       is_active BOOLEAN DEFAULT 1,
       auto_record BOOLEAN DEFAULT 1,
       quality_preference TEXT DEFAULT 'best',
+      record_schedule TEXT,
       created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
       updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
     )
@@ -162,6 +163,15 @@ function createTables(db: DatabaseType): void {
       max_total_recordings_mb INTEGER DEFAULT 0,
       max_recording_duration_hours INTEGER DEFAULT 0,
       check_interval_seconds INTEGER DEFAULT 60,
+      stall_timeout_seconds INTEGER DEFAULT 300,
+      notify_webhook_url TEXT,
+      quality_in_filename INTEGER DEFAULT 0,
+      rotate_daily INTEGER DEFAULT 0,
+      rotate_on_title_change INTEGER DEFAULT 0,
+      per_streamer_dirs INTEGER DEFAULT 0,
+      record_chat INTEGER DEFAULT 0,
+      metadata_sidecar INTEGER DEFAULT 0,
+      allow_audio_only INTEGER DEFAULT 0,
       updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
     )
   `);